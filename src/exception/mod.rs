@@ -0,0 +1,194 @@
+//! Ruby exceptions.
+
+use std::fmt;
+use crate::{
+    object::Ty,
+    prelude::*,
+    ruby,
+};
+
+/// A type that represents a raised (or raisable) Ruby exception.
+pub trait Exception: Object {
+    /// Returns `self` as an `AnyException`.
+    #[inline]
+    fn as_any_exception(self) -> AnyException {
+        unsafe { AnyException::from_raw(self.raw()) }
+    }
+}
+
+impl Exception for AnyException {}
+
+/// An instance of an object whose class conforms to Ruby's `Exception`.
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct AnyException(AnyObject);
+
+unsafe impl Object for AnyException {
+    #[inline]
+    fn cast(obj: impl Object) -> Option<Self> {
+        obj.into_any_object().to_exception()
+    }
+
+    #[inline]
+    unsafe fn from_raw(raw: ruby::VALUE) -> Self {
+        AnyException(AnyObject::from_raw(raw))
+    }
+}
+
+impl AsRef<AnyObject> for AnyException {
+    #[inline]
+    fn as_ref(&self) -> &AnyObject { &self.0 }
+}
+
+impl From<AnyException> for AnyObject {
+    #[inline]
+    fn from(exc: AnyException) -> Self { exc.0 }
+}
+
+impl fmt::Debug for AnyException {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for AnyException {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+// `AnyException` is `#[repr(transparent)]` over a single `VALUE` (see
+// `RosyObject`'s own size assertions for why that layout matters), so there's
+// no room to cache a `cause` to hand back as a long-lived borrow, and
+// `cause`/`Exception#cause` is recomputed by calling back into Ruby every
+// time rather than being stored on `self`. `source` is left as the default
+// (`None`) rather than leaking a `Box` per call to manufacture one; use
+// [`cause`](#method.cause)/[`causes`](#method.causes) to walk the chain
+// directly instead.
+impl std::error::Error for AnyException {}
+
+/// An iterator over an exception's `cause` chain, starting with the
+/// exception it was created from.
+///
+/// See [`AnyException::causes`](struct.AnyException.html#method.causes).
+pub struct Causes(Option<AnyException>);
+
+impl Iterator for Causes {
+    type Item = AnyException;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let current = self.0.take()?;
+        self.0 = current.cause();
+        Some(current)
+    }
+}
+
+impl AnyException {
+    #[inline]
+    pub(crate) fn _new(raw: ruby::VALUE) -> Self {
+        Self(AnyObject::from(raw))
+    }
+
+    /// Takes the exception currently stored by the VM (`rb_errinfo`),
+    /// clearing it from the VM in the process.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the VM actually has a pending exception;
+    /// otherwise the returned value will be `nil`-backed garbage.
+    #[inline]
+    pub(crate) unsafe fn _take_current() -> Self {
+        let raw = ruby::rb_errinfo();
+        ruby::rb_set_errinfo(crate::util::NIL_VALUE);
+        Self::_new(raw)
+    }
+
+    /// Raises `self`, unwinding the Ruby call stack up to the nearest
+    /// `rb_protect`/`rb_rescue` frame.
+    ///
+    /// # Safety
+    ///
+    /// This performs a non-local jump via `longjmp` and therefore never
+    /// returns normally; Rust destructors between here and that frame will
+    /// not run.
+    #[inline]
+    pub unsafe fn raise(self) -> ! {
+        ruby::rb_exc_raise(self.raw())
+    }
+
+    /// Returns whether `self` is a `NameError`.
+    #[inline]
+    pub fn is_name_error(self) -> bool {
+        self.class().inherits(Class::name_error())
+    }
+
+    /// Returns whether `self` is a `TypeError`.
+    #[inline]
+    pub fn is_type_error(self) -> bool {
+        self.class().inherits(Class::type_error())
+    }
+
+    /// Returns the result of calling `message` on `self`.
+    #[inline]
+    pub fn message(self) -> crate::String {
+        unsafe { self.call_unchecked("message") }.to_s()
+    }
+
+    /// Returns the frames of `self`'s backtrace, or an empty `Vec` if none
+    /// was captured.
+    ///
+    /// See [`vm::backtrace_locations`](../vm/fn.backtrace_locations.html) for
+    /// capturing the current call stack instead of an exception's own.
+    #[inline]
+    pub fn backtrace_locations(self) -> Vec<crate::vm::BacktraceLocation> {
+        crate::vm::_locations_of(self.into_any_object())
+    }
+
+    /// Returns the exception that caused `self` to be raised, set by Ruby
+    /// whenever one exception's handler raises another (`Exception#cause`).
+    #[inline]
+    pub fn cause(self) -> Option<Self> {
+        unsafe { self.call_unchecked("cause") }.to_exception()
+    }
+
+    /// Returns an iterator that walks `self`'s `cause` chain, starting with
+    /// `self`.
+    #[inline]
+    pub fn causes(self) -> Causes {
+        Causes(Some(self))
+    }
+
+    /// Builds a `RuntimeError` with `message`, used to surface a Rust panic
+    /// that unwound out of a method defined via
+    /// [`MethodDef::def_method`](../mixin/trait.MethodDef.html#method.def_method)
+    /// and a failed transcode in `String::encode_from`.
+    #[inline]
+    pub(crate) fn _runtime_error(message: &str) -> Self {
+        let message = crate::String::from(message);
+        let args: &[AnyObject] = &[message.into()];
+        unsafe {
+            Self::from_raw(Class::runtime_error().new_instance_with(args).raw())
+        }
+    }
+
+    /// Builds an `ArgumentError` reporting that `given` arguments were
+    /// passed to a Rust-implemented method with the arity `expected`.
+    #[inline]
+    pub(crate) fn _arg_count_error(given: usize, expected: crate::mixin::Arity) -> Self {
+        let message = match expected {
+            crate::mixin::Arity::Fixed(n) => crate::String::from(format!(
+                "wrong number of arguments (given {}, expected {})", given, n,
+            )),
+            crate::mixin::Arity::Splat => crate::String::from(format!(
+                "wrong number of arguments (given {})", given,
+            )),
+        };
+        let args: &[AnyObject] = &[message.into()];
+        unsafe {
+            Self::from_raw(Class::argument_error().new_instance_with(args).raw())
+        }
+    }
+}
@@ -0,0 +1,266 @@
+//! Defining methods implemented by Rust closures.
+
+use std::{
+    ffi::CString,
+    os::raw::{c_char, c_int},
+    panic::{self, AssertUnwindSafe},
+};
+use crate::{
+    object::RosyObject,
+    prelude::*,
+    rosy::Rosy,
+    ruby::{self, VALUE},
+};
+use super::Mixin;
+
+/// The number of arguments a Rust-implemented method accepts.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Arity {
+    /// Accepts exactly this many arguments; any other count raises an
+    /// `ArgumentError` before the closure is even called.
+    Fixed(u8),
+    /// Accepts any number of arguments, mirroring Ruby's `*args` and the
+    /// `-1` `argc` Ruby passes to `rb_define_method` for such methods.
+    Splat,
+}
+
+impl Arity {
+    #[inline]
+    fn matches(self, len: usize) -> bool {
+        match self {
+            Arity::Fixed(n) => len == n as usize,
+            Arity::Splat => true,
+        }
+    }
+}
+
+// The boxed closure underlying a method defined via `MethodDef::def_method`.
+//
+// This is wrapped in a `RosyObject` and stashed as an instance variable on
+// the defining class/module so the VM keeps it alive for as long as the
+// method exists. Objects the closure captures are not marked through it
+// (see `mark` below). Ruby's
+// `rb_define_method` has no userdata slot of its own, so every method
+// registered this way shares one non-generic trampoline that looks its
+// `MethodFn` back up by method name at call time.
+struct MethodFn {
+    arity: Arity,
+    call: Box<dyn Fn(AnyObject, &[AnyObject]) -> crate::Result<AnyObject>>,
+}
+
+impl Rosy for MethodFn {
+    const ID: *const std::os::raw::c_char =
+        b"rosy::mixin::method::MethodFn\0".as_ptr() as *const _;
+
+    #[inline]
+    fn class() -> Class<RosyObject<Self>> {
+        // Never exposed to Ruby; `Object` itself is used as the wrapping
+        // class since instances of this type are never seen by Ruby code.
+        unsafe { Class::from_raw(Class::object().raw()) }
+    }
+
+    #[inline]
+    fn mark(&mut self) {
+        // `AnyObject`s captured by `call` are kept alive by whatever stored
+        // them there (e.g. a constant); nothing further to mark here.
+    }
+}
+
+#[inline]
+fn _data_ivar(name: &str) -> SymbolId {
+    SymbolId::from(format!("@__rosy_method_fn_{}", name))
+}
+
+unsafe fn _panic_message(payload: &(dyn std::any::Any + Send)) -> std::string::String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).into()
+    } else if let Some(s) = payload.downcast_ref::<std::string::String>() {
+        s.clone()
+    } else {
+        "Rust method panicked".into()
+    }
+}
+
+// `_def_method` stashes the `MethodFn` ivar on whichever class/module
+// `def_method` was originally called on, not on every class it later ends up
+// reachable from. A method defined on a module and `include`d into a class,
+// or defined on a superclass and invoked through a subclass instance, is
+// called with a `receiver.class()` that never directly owns that ivar (Ruby
+// ivars on a class/module object are not inherited through the ancestry
+// chain). So walk `receiver.class()`'s ancestors (nearest first, exactly the
+// order Ruby itself would search for the method) until one of them actually
+// has the ivar set. This reads the raw ancestors array directly through
+// `rb_array_len`/`rb_ary_entry` rather than going through the
+// `Array<Module>` that `Mixin::ancestors` returns.
+unsafe fn _find_method_fn(class: VALUE, ivar: SymbolId) -> AnyObject {
+    let ancestors = ruby::rb_mod_ancestors(class);
+    let len = ruby::rb_array_len(ancestors);
+    for i in 0..len {
+        let owner = ruby::rb_ary_entry(ancestors, i);
+        let data = AnyObject::from_raw(ruby::rb_ivar_get(owner, ivar.raw()));
+        if !data.is_nil() {
+            return data;
+        }
+    }
+    // Should be unreachable: the trampoline is only ever registered by
+    // `_def_method`, which always stashes this ivar on some ancestor before
+    // `rb_define_*` can make the method callable.
+    AnyException::_runtime_error("rosy: method implementation not found").raise();
+}
+
+unsafe extern "C" fn _call(argc: c_int, argv: *const VALUE, recv: VALUE) -> VALUE {
+    let receiver = AnyObject::from_raw(recv);
+    let name = std::ffi::CStr::from_ptr(ruby::rb_id2name(ruby::rb_frame_this_func()));
+    let ivar = _data_ivar(&name.to_string_lossy());
+    let data = _find_method_fn(receiver.class().raw(), ivar);
+    let data = RosyObject::<MethodFn>::cast_unchecked(data);
+    let func = data.as_data();
+
+    let args = std::slice::from_raw_parts(argv as *const AnyObject, argc as usize);
+
+    if !func.arity.matches(args.len()) {
+        AnyException::_arg_count_error(args.len(), func.arity).raise();
+    }
+
+    // Run the closure behind a panic guard: we must unwind fully back to
+    // this frame before raising, since longjmp-ing across live Rust frames
+    // (as `rb_exc_raise` would if called mid-unwind) is undefined behavior.
+    match panic::catch_unwind(AssertUnwindSafe(|| (func.call)(receiver, args))) {
+        Ok(Ok(value)) => value.raw(),
+        Ok(Err(exc)) => exc.raise(),
+        Err(payload) => {
+            let message = _panic_message(&*payload);
+            AnyException::_runtime_error(&message).raise();
+        }
+    }
+}
+
+// The common body behind `def_method` and its visibility-flavored siblings:
+// stash `f` in an ivar keyed by method name, then register the trampoline
+// via whichever `rb_define_*` function matches the desired visibility.
+fn _def_method<M, N, F, T>(
+    m: M,
+    name: N,
+    arity: Arity,
+    f: F,
+    define: unsafe extern "C" fn(
+        VALUE,
+        *const c_char,
+        unsafe extern "C" fn(c_int, *const VALUE, VALUE) -> VALUE,
+        c_int,
+    ),
+) where
+    M: Mixin,
+    N: Into<SymbolId>,
+    F: Fn(AnyObject, &[AnyObject]) -> crate::Result<T> + 'static,
+    T: Into<AnyObject>,
+{
+    let name = name.into();
+    let name_str = name.to_string();
+    let c_name = CString::new(name_str.clone()).expect("method name has a nul byte");
+    let func = MethodFn {
+        arity,
+        call: Box::new(move |recv, args| f(recv, args).map(Into::into)),
+    };
+    let data: RosyObject<MethodFn> = func.into();
+    unsafe {
+        ruby::rb_ivar_set(
+            m.raw(),
+            _data_ivar(&name_str).raw(),
+            data.raw(),
+        );
+        define(
+            m.raw(),
+            c_name.as_ptr(),
+            _call,
+            match arity {
+                Arity::Fixed(n) => n as c_int,
+                Arity::Splat => -1,
+            },
+        );
+    }
+}
+
+impl<M: Mixin> MethodDef for M {}
+
+/// Defining methods implemented by Rust closures on a [`Mixin`](trait.Mixin.html).
+pub trait MethodDef: Mixin {
+    /// Defines a method named `name` on `self` that calls `f` whenever it is
+    /// invoked from Ruby.
+    ///
+    /// `f` receives the method's receiver along with its arguments and
+    /// returns a [`Result`](../type.Result.html); an `Err` is raised as a
+    /// Ruby exception, and a Rust panic is converted into a `RuntimeError`.
+    /// Both happen only after Rust's stack has fully unwound back to the
+    /// trampoline, never via a raw `longjmp` over live Rust frames.
+    #[inline]
+    fn def_method<N, F, T>(self, name: N, arity: Arity, f: F)
+    where
+        N: Into<SymbolId>,
+        F: Fn(AnyObject, &[AnyObject]) -> crate::Result<T> + 'static,
+        T: Into<AnyObject>,
+    {
+        _def_method(self, name, arity, f, ruby::rb_define_method);
+    }
+
+    /// Defines a private method named `name` on `self` that calls `f`
+    /// whenever it is invoked from Ruby.
+    ///
+    /// See [`def_method`](#method.def_method) for the calling convention.
+    #[inline]
+    fn def_private_method<N, F, T>(self, name: N, arity: Arity, f: F)
+    where
+        N: Into<SymbolId>,
+        F: Fn(AnyObject, &[AnyObject]) -> crate::Result<T> + 'static,
+        T: Into<AnyObject>,
+    {
+        _def_method(self, name, arity, f, ruby::rb_define_private_method);
+    }
+
+    /// Defines a protected method named `name` on `self` that calls `f`
+    /// whenever it is invoked from Ruby.
+    ///
+    /// See [`def_method`](#method.def_method) for the calling convention.
+    #[inline]
+    fn def_protected_method<N, F, T>(self, name: N, arity: Arity, f: F)
+    where
+        N: Into<SymbolId>,
+        F: Fn(AnyObject, &[AnyObject]) -> crate::Result<T> + 'static,
+        T: Into<AnyObject>,
+    {
+        _def_method(self, name, arity, f, ruby::rb_define_protected_method);
+    }
+
+    /// Changes the visibility of the already-defined method `name` on
+    /// `self` to `vis`.
+    ///
+    /// This works for any method, not just ones defined via `def_method` and
+    /// its siblings, by calling back into `Module#public`/`#private`/
+    /// `#protected`/`#module_function`.
+    #[inline]
+    fn set_method_visibility(self, name: impl Into<SymbolId>, vis: Visibility) {
+        let name = Symbol::from(name.into());
+        let method = match vis {
+            Visibility::Public => "public",
+            Visibility::Private => "private",
+            Visibility::Protected => "protected",
+            Visibility::ModuleFunction => "module_function",
+        };
+        unsafe { self.call_with(method, &[name]) };
+    }
+}
+
+/// The visibility of a method defined on a [`Mixin`](trait.Mixin.html).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Visibility {
+    /// Callable with an explicit receiver, the default for most methods.
+    Public,
+    /// Only callable without an explicit receiver.
+    Private,
+    /// Only callable with an explicit receiver from within the same class
+    /// hierarchy.
+    Protected,
+    /// Callable as both a private instance method and a public method on the
+    /// defining module itself, as with `Math.sqrt`.
+    ModuleFunction,
+}
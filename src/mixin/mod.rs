@@ -28,9 +28,35 @@ unsafe fn _get_const_unchecked(m: impl Mixin, name: impl Into<SymbolId>) -> AnyO
     AnyObject::from_raw(ruby::rb_const_get(m.raw(), name.into().raw()))
 }
 
-// monomorphization
-unsafe fn _set_attr(m: VALUE, name: ID, read: bool, write: bool) -> Result {
-    crate::protected_no_panic(|| _set_attr_unchecked(m, name, read, write))
+#[inline]
+fn _is_frozen(m: VALUE) -> bool {
+    unsafe { AnyObject::from_raw(ruby::rb_obj_frozen_p(m)) }.is_true()
+}
+
+// Builds a `TypeError` directly the same cheap way
+// `AnyException::_runtime_error`/`_arg_count_error` do, instead of a full
+// eval/parse/raise/rescue round trip, for a failed `get_const_as`/
+// `get_class_var_as` conversion. Names the constant/variable that failed to
+// convert and the type it was expected to be an instance of.
+fn _conversion_error<T>(name: SymbolId) -> AnyException {
+    let name = unsafe { std::ffi::CStr::from_ptr(ruby::rb_id2name(name.raw())) };
+    let message = crate::String::from(&format!(
+        "wrong argument type for `{}` (expected an instance of `{}`)",
+        name.to_string_lossy(),
+        std::any::type_name::<T>(),
+    ));
+    let args: &[AnyObject] = &[message.into()];
+    unsafe {
+        AnyException::from_raw(Class::type_error().new_instance_with(args).raw())
+    }
+}
+
+fn _set_attr(m: impl Mixin, name: ID, read: bool, write: bool) -> Result<(), DefMixinError> {
+    if _is_frozen(m.raw()) {
+        return Err(DefMixinError::_frozen(m));
+    }
+    unsafe { _set_attr_unchecked(m.raw(), name, read, write) };
+    Ok(())
 }
 
 #[inline]
@@ -39,8 +65,23 @@ unsafe fn _set_attr_unchecked(m: VALUE, name: ID, read: bool, write: bool) {
 }
 
 // monomorphization
-unsafe fn _set_class_var(m: VALUE, key: ID, val: VALUE) -> Result {
-    crate::protected_no_panic(|| _set_class_var_unchecked(m, key, val))
+unsafe fn _def_alias(m: VALUE, dst: ID, src: ID) -> Result {
+    crate::protected::exception_only(
+        crate::protected_no_panic(|| _def_alias_unchecked(m, dst, src)),
+    )
+}
+
+#[inline]
+unsafe fn _def_alias_unchecked(m: VALUE, dst: ID, src: ID) {
+    ruby::rb_alias(m, dst, src);
+}
+
+fn _set_class_var(m: impl Mixin, key: ID, val: VALUE) -> Result<(), DefMixinError> {
+    if _is_frozen(m.raw()) {
+        return Err(DefMixinError::_frozen(m));
+    }
+    unsafe { _set_class_var_unchecked(m.raw(), key, val) };
+    Ok(())
 }
 
 #[inline]
@@ -78,6 +119,32 @@ pub trait Mixin: Object + Sealed {
         unsafe { Array::from_raw(ruby::rb_mod_included_modules(self.raw())) }
     }
 
+    /// Returns the list of classes and modules searched for method
+    /// resolution in `self`, ordered from nearest to furthest.
+    ///
+    /// This includes any modules prepended to or included in `self`, `self`
+    /// itself, and all of its ancestors.
+    #[inline]
+    fn ancestors(self) -> Array<Module> {
+        unsafe { Array::from_raw(ruby::rb_mod_ancestors(self.raw())) }
+    }
+
+    /// Returns whether `self` is `other` or one of its descendants.
+    #[inline]
+    #[must_use]
+    fn inherits(self, other: Class) -> bool {
+        let raw = unsafe { ruby::rb_class_inherited_p(self.raw(), other.raw()) };
+        unsafe { AnyObject::from_raw(raw) }.is_true()
+    }
+
+    /// Returns whether `self` is `other` or one of its ancestors.
+    #[inline]
+    #[must_use]
+    fn is_ancestor_of(self, other: Class) -> bool {
+        let raw = unsafe { ruby::rb_class_inherited_p(other.raw(), self.raw()) };
+        unsafe { AnyObject::from_raw(raw) }.is_true()
+    }
+
     /// Prepends `module` in `self`.
     #[inline]
     fn prepend(self, module: Module) {
@@ -85,24 +152,67 @@ pub trait Mixin: Object + Sealed {
     }
 
     /// Defines a new class under `self` with `name`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
     fn def_class(
         self,
         name: impl Into<SymbolId>,
     ) -> Result<Class, DefMixinError> {
+        if _is_frozen(self.raw()) {
+            return Err(DefMixinError::_frozen(self));
+        }
         Class::_def_under(self, Class::object(), name.into())
     }
 
     /// Defines a new subclass of `superclass` under `self` with `name`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
     fn def_subclass<S: Object>(
         self,
         superclass: Class<S>,
         name: impl Into<SymbolId>,
     ) -> Result<Class, DefMixinError> {
+        if _is_frozen(self.raw()) {
+            return Err(DefMixinError::_frozen(self));
+        }
         Class::_def_under(self, superclass.into_any_class(), name.into())
     }
 
+    /// Defines a new subclass of `superclass` under `self` with `name`, for
+    /// use as a custom exception type that can be directly raised.
+    ///
+    /// Pass [`Class::standard_error()`](struct.Class.html#method.standard_error)
+    /// for `superclass` to get the same `StandardError` base every built-in
+    /// `raise Class, "message"` gets; pass something else (such as
+    /// `Class::runtime_error()`) to subclass that instead.
+    ///
+    /// Unlike [`def_subclass`](#method.def_subclass), which hands back a
+    /// plain `Class`, this returns a `Class<AnyException>` so the result can
+    /// be built and raised directly without the caller casting it first.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
+    #[inline]
+    fn def_exception_class<S: Exception>(
+        self,
+        name: impl Into<SymbolId>,
+        superclass: Class<S>,
+    ) -> Result<Class<AnyException>, DefMixinError> {
+        if _is_frozen(self.raw()) {
+            return Err(DefMixinError::_frozen(self));
+        }
+        let class = Class::_def_under(self, superclass.into_any_class(), name.into())?;
+        // `_def_under` only ever hands back a plain `Class<AnyObject>`; this
+        // is sound here because `superclass` is itself a real exception
+        // class, so every instance of the class just defined under it is
+        // one too.
+        Ok(unsafe { Class::cast_unchecked(class.into_any_object()) })
+    }
+
     /// Returns the existing `Class` with `name` in `self`.
     #[inline]
     fn get_class(
@@ -129,11 +239,17 @@ pub trait Mixin: Object + Sealed {
     }
 
     /// Defines a new module under `self` with `name`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
     fn def_module(
         self,
         name: impl Into<SymbolId>,
     ) -> Result<Module, DefMixinError> {
+        if _is_frozen(self.raw()) {
+            return Err(DefMixinError::_frozen(self));
+        }
         Module::_def_under(self, name.into())
     }
 
@@ -184,6 +300,24 @@ pub trait Mixin: Object + Sealed {
         unsafe { AnyObject::from_raw(ruby::rb_const_get(self.raw(), name)) }
     }
 
+    /// Returns the constant value for `name` in `self` converted to `T`, or
+    /// in some parent class if not `self`.
+    ///
+    /// Unlike [`get_const`](#method.get_const), a missing constant surfaces
+    /// as a `NameError` through the returned `Err` rather than unwinding as
+    /// a raised exception. If the constant exists but isn't an instance of
+    /// `T`, a `TypeError` is returned instead.
+    #[inline]
+    fn get_const_as<T: Object>(self, name: impl Into<SymbolId>) -> Result<T> {
+        let name = name.into();
+        let obj = unsafe {
+            crate::protected::exception_only(
+                crate::protected_no_panic(|| _get_const_unchecked(self, name)),
+            )
+        }?;
+        T::cast(obj).ok_or_else(|| _conversion_error::<T>(name))
+    }
+
     /// Sets the value a constant for `name` in `self` to `val`.
     #[inline]
     fn set_const(self, name: impl Into<SymbolId>, val: impl Into<AnyObject>) {
@@ -232,16 +366,36 @@ pub trait Mixin: Object + Sealed {
         unsafe { AnyObject::from_raw(ruby::rb_cvar_get(self.raw(), var)) }
     }
 
+    /// Returns the class-level `var` in `self` converted to `T`.
+    ///
+    /// Unlike [`get_class_var`](#method.get_class_var), an uninitialized
+    /// `var` surfaces as a `NameError` through the returned `Err` rather
+    /// than unwinding as a raised exception. If `var` exists but isn't an
+    /// instance of `T`, a `TypeError` is returned instead.
+    #[inline]
+    fn get_class_var_as<T: Object>(self, var: impl Into<SymbolId>) -> Result<T> {
+        let var = var.into();
+        let obj = unsafe {
+            crate::protected::exception_only(
+                crate::protected_no_panic(|| AnyObject::from_raw(ruby::rb_cvar_get(self.raw(), var.raw()))),
+            )
+        }?;
+        T::cast(obj).ok_or_else(|| _conversion_error::<T>(var))
+    }
+
     /// Sets the class-level `var` in `self` to `val`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
-    fn set_class_var<K, V>(self, key: K, val: V) -> Result
+    fn set_class_var<K, V>(self, key: K, val: V) -> Result<(), DefMixinError>
     where
         K: Into<SymbolId>,
         V: Into<AnyObject>,
     {
         let key = key.into().raw();
         let val = val.into().raw();
-        unsafe { _set_class_var(self.raw(), key, val) }
+        _set_class_var(self, key, val)
     }
 
     /// Sets the class-level var for `key` in `self` to `val`.
@@ -260,9 +414,12 @@ pub trait Mixin: Object + Sealed {
     }
 
     /// Defines an read-only attribute on `self` with `name`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
-    fn def_attr_reader<N: Into<SymbolId>>(self, name: N) -> Result {
-        unsafe { _set_attr(self.raw(), name.into().raw(), true, false) }
+    fn def_attr_reader<N: Into<SymbolId>>(self, name: N) -> Result<(), DefMixinError> {
+        _set_attr(self, name.into().raw(), true, false)
     }
 
     /// Defines an read-only attribute on `self` with `name`.
@@ -277,9 +434,12 @@ pub trait Mixin: Object + Sealed {
     }
 
     /// Defines a write-only attribute on `self` with `name`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
-    fn def_attr_writer<N: Into<SymbolId>>(self, name: N) -> Result {
-        unsafe { _set_attr(self.raw(), name.into().raw(), false, true) }
+    fn def_attr_writer<N: Into<SymbolId>>(self, name: N) -> Result<(), DefMixinError> {
+        _set_attr(self, name.into().raw(), false, true)
     }
 
     /// Defines a write-only attribute on `self` with `name`.
@@ -294,9 +454,12 @@ pub trait Mixin: Object + Sealed {
     }
 
     /// Defines a read-write attribute on `self` with `name`.
+    ///
+    /// Returns `DefMixinError::FrozenClass`/`FrozenModule` if `self` is frozen
+    /// rather than letting Ruby raise a `FrozenError`.
     #[inline]
-    fn def_attr_accessor<N: Into<SymbolId>>(self, name: N) -> Result {
-        unsafe { _set_attr(self.raw(), name.into().raw(), true, true) }
+    fn def_attr_accessor<N: Into<SymbolId>>(self, name: N) -> Result<(), DefMixinError> {
+        _set_attr(self, name.into().raw(), true, true)
     }
 
     /// Defines a read-write attribute on `self` with `name`.
@@ -310,6 +473,34 @@ pub trait Mixin: Object + Sealed {
         _set_attr_unchecked(self.raw(), name.into().raw(), true, true);
     }
 
+    /// Aliases the method `src` to be also callable as `dst` on `self`.
+    #[inline]
+    fn def_alias<D, S>(self, dst: D, src: S) -> Result
+    where
+        D: Into<SymbolId>,
+        S: Into<SymbolId>,
+    {
+        let dst = dst.into().raw();
+        let src = src.into().raw();
+        unsafe { _def_alias(self.raw(), dst, src) }
+    }
+
+    /// Aliases the method `src` to be also callable as `dst` on `self`,
+    /// without checking whether `self` is frozen.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen or else a `FrozenError`
+    /// exception will be raised.
+    #[inline]
+    unsafe fn def_alias_unchecked<D, S>(self, dst: D, src: S)
+    where
+        D: Into<SymbolId>,
+        S: Into<SymbolId>,
+    {
+        _def_alias_unchecked(self.raw(), dst.into().raw(), src.into().raw());
+    }
+
     /// Evaluates `args` in the context of `self`.
     ///
     /// See the docs for `EvalArgs` for more info.
@@ -240,4 +240,203 @@ impl Float {
     pub fn to_f32(self) -> f32 {
         self.to_f64() as f32
     }
+
+    /// Returns the IEEE 754 `totalOrder` comparison between `self` and
+    /// `other`.
+    ///
+    /// Unlike [`partial_cmp`](#impl-PartialOrd%3CFloat%3E), this always
+    /// returns an `Ordering`: `-0.0` sorts below `+0.0`, every negative
+    /// value sorts below every positive one, and NaNs sort at the extremes
+    /// (by sign and payload) instead of comparing unordered. This makes it
+    /// usable to order `Float`s deterministically, e.g. for `sort_by` or as
+    /// a `BTreeMap`/`BTreeSet` key via [`TotalOrd`](struct.TotalOrd.html).
+    #[inline]
+    pub fn total_cmp(self, other: Float) -> Ordering {
+        #[inline]
+        fn key(f: f64) -> i64 {
+            let mut bits = f.to_bits() as i64;
+            bits ^= (((bits >> 63) as u64) >> 1) as i64;
+            bits
+        }
+        key(self.to_f64()).cmp(&key(other.to_f64()))
+    }
+
+    /// Returns Ruby's `Float::NAN`.
+    #[inline]
+    pub fn nan() -> Float {
+        f64::NAN.into()
+    }
+
+    /// Returns Ruby's `Float::INFINITY`.
+    #[inline]
+    pub fn infinity() -> Float {
+        f64::INFINITY.into()
+    }
+
+    /// Returns the negation of Ruby's `Float::INFINITY`.
+    #[inline]
+    pub fn neg_infinity() -> Float {
+        f64::NEG_INFINITY.into()
+    }
+
+    /// Returns whether `self` is `NaN`.
+    #[inline]
+    pub fn is_nan(self) -> bool {
+        self.to_f64().is_nan()
+    }
+
+    /// Returns whether `self` is positive or negative infinity.
+    #[inline]
+    pub fn is_infinite(self) -> bool {
+        self.to_f64().is_infinite()
+    }
+
+    /// Returns whether `self` is neither infinite nor `NaN`.
+    #[inline]
+    pub fn is_finite(self) -> bool {
+        self.to_f64().is_finite()
+    }
+
+    /// Returns whether `self` is neither zero, infinite, subnormal, nor
+    /// `NaN`.
+    #[inline]
+    pub fn is_normal(self) -> bool {
+        self.to_f64().is_normal()
+    }
+
+    /// Returns the IEEE 754 floating-point category of `self`.
+    #[inline]
+    pub fn classify(self) -> std::num::FpCategory {
+        self.to_f64().classify()
+    }
+
+    /// Returns a number that represents the sign of `self`.
+    ///
+    /// See [`f64::signum`](https://doc.rust-lang.org/std/primitive.f64.html#method.signum)
+    /// for the exact `1.0`/`-1.0`/`NaN` semantics.
+    #[inline]
+    pub fn signum(self) -> Float {
+        self.to_f64().signum().into()
+    }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Float {
+        self.to_f64().abs().into()
+    }
+
+    /// Returns `self` rounded down to the nearest whole number, as an
+    /// [`Integer`](struct.Integer.html).
+    ///
+    /// Unlike converting through an `i64`, this doesn't overflow for values
+    /// outside that range; it produces a `Bignum` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `NaN` or infinite, matching the
+    /// `FloatDomainError` Ruby's own `Float#floor` raises for the same
+    /// input.
+    #[inline]
+    pub fn to_integer_floor(self) -> Integer {
+        self._checked_to_integer(f64::floor, "floor")
+    }
+
+    /// Returns `self` rounded up to the nearest whole number, as an
+    /// [`Integer`](struct.Integer.html).
+    ///
+    /// Unlike converting through an `i64`, this doesn't overflow for values
+    /// outside that range; it produces a `Bignum` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `NaN` or infinite, matching the
+    /// `FloatDomainError` Ruby's own `Float#ceil` raises for the same input.
+    #[inline]
+    pub fn to_integer_ceil(self) -> Integer {
+        self._checked_to_integer(f64::ceil, "ceil")
+    }
+
+    /// Returns `self` rounded to the nearest whole number, as an
+    /// [`Integer`](struct.Integer.html), with ties rounding away from zero
+    /// (see [`f64::round`](https://doc.rust-lang.org/std/primitive.f64.html#method.round)).
+    ///
+    /// Unlike converting through an `i64`, this doesn't overflow for values
+    /// outside that range; it produces a `Bignum` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `NaN` or infinite, matching the
+    /// `FloatDomainError` Ruby's own `Float#round` raises for the same
+    /// input.
+    #[inline]
+    pub fn to_integer_round(self) -> Integer {
+        self._checked_to_integer(f64::round, "round")
+    }
+
+    /// Returns `self` rounded toward zero to the nearest whole number, as an
+    /// [`Integer`](struct.Integer.html).
+    ///
+    /// Unlike converting through an `i64`, this doesn't overflow for values
+    /// outside that range; it produces a `Bignum` instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is `NaN` or infinite, matching the
+    /// `FloatDomainError` Ruby's own `Float#truncate` raises for the same
+    /// input.
+    #[inline]
+    pub fn to_integer_truncate(self) -> Integer {
+        self._checked_to_integer(f64::trunc, "truncate")
+    }
+
+    // `rb_dbl2big` raises `FloatDomainError` for NaN/±Infinity, the same as
+    // `Float#to_i`/`#floor`/etc. Since none of the four callers above are
+    // `unsafe fn`/`Result`-returning, a non-finite `self` is reported as a
+    // clean Rust panic instead of an unguarded `longjmp` through safe code.
+    #[inline]
+    fn _checked_to_integer(self, round: fn(f64) -> f64, op: &str) -> Integer {
+        let n = self.to_f64();
+        assert!(n.is_finite(), "cannot {} a non-finite Float ({:?})", op, n);
+        unsafe { Integer::from_raw(ruby::rb_dbl2big(round(n))) }
+    }
+}
+
+/// A [`Float`](struct.Float.html) wrapper that orders by
+/// [`total_cmp`](struct.Float.html#method.total_cmp) rather than the
+/// NaN-sensitive `PartialOrd`/`PartialEq` `Float` itself uses, so it can be
+/// used as a `BTreeMap`/`BTreeSet` key or sorted deterministically.
+///
+/// `Eq` and `Ord` are derived from `total_cmp` here (not `Float`'s own
+/// `PartialEq`), since an `Ord` impl must agree with its `Eq`.
+#[derive(Clone, Copy, Debug)]
+pub struct TotalOrd(pub Float);
+
+impl PartialEq for TotalOrd {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for TotalOrd {}
+
+impl PartialOrd for TotalOrd {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for TotalOrd {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0.total_cmp(other.0)
+    }
+}
+
+impl From<Float> for TotalOrd {
+    #[inline]
+    fn from(float: Float) -> Self {
+        TotalOrd(float)
+    }
 }
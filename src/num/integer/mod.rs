@@ -2,12 +2,13 @@
 
 use std::{
     cmp::Ordering,
-    ffi::c_void,
+    ffi::{c_void, CString},
     fmt,
     mem,
     ops,
     os::raw::c_int,
     slice,
+    str::FromStr,
 };
 use crate::{
     prelude::*,
@@ -18,6 +19,9 @@ use crate::{
 pub mod pack;
 use pack::Word;
 
+#[cfg(feature = "num-traits")]
+mod num_traits_impl;
+
 /// An instance of Ruby's `Integer` class.
 ///
 /// This type supports conversions to/from _all_ of Rust's integer primitives,
@@ -25,6 +29,33 @@ use pack::Word;
 /// over a buffer of `Word`s via [`pack`](#method.pack) and
 /// [`unpack`](#method.unpack).
 ///
+/// # Arithmetic
+///
+/// `Add`, `Sub`, `Mul`, `Div`, `Rem`, and `Neg` are all supported, with a
+/// fixnum fast path the same way the logical operations below have one; each
+/// also works directly against Rust's integer primitives on either side, so
+/// there's no need to wrap one in an `Integer` first:
+///
+/// ```
+/// # rosy::vm::init().unwrap();
+/// # rosy::protected(|| {
+/// use rosy::Integer;
+///
+/// let a = Integer::from(7);
+/// let b = Integer::from(2);
+///
+/// assert_eq!(a + b, 9);
+/// assert_eq!(a - b, 5);
+/// assert_eq!(a * b, 14);
+/// assert_eq!(a / b, 3);
+/// assert_eq!(a % b, 1);
+/// assert_eq!(-a, -7);
+///
+/// assert_eq!(a + 1, 8);
+/// assert_eq!(1 + a, 8);
+/// # }).unwrap();
+/// ```
+///
 /// # Logical Binary Operations
 ///
 /// The logical operations [AND], [OR], and [XOR] are all supported:
@@ -316,6 +347,232 @@ impl_bit_ops! {
     BitXor, bitxor, rb_big_xor;
 }
 
+impl ops::Shl<u32> for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn shl(self, rhs: u32) -> Self {
+        if let Some(a) = self.to_fixnum() {
+            if let Some(n) = a.checked_shl(rhs).filter(|&n| n >> rhs == a && n << 1 >> 1 == n) {
+                return Self::from_fixnum_wrapping(n);
+            }
+        }
+        // Missing the fast path only means the *result* can't be
+        // expressed as a fixnum, e.g. shifting a small Fixnum like `1` by
+        // `200` still has `self` itself as a Fixnum, which `rb_big_lshift`
+        // cannot take as its receiver. Fall back to a real method call
+        // rather than assuming `self` is already a Bignum.
+        unsafe { self.call_with("<<", &[Self::from(rhs)]) }
+            .to_integer()
+            .expect("`<<` did not return an Integer")
+    }
+}
+
+impl ops::Shr<u32> for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn shr(self, rhs: u32) -> Self {
+        if let Some(a) = self.to_fixnum() {
+            if let Some(n) = a.checked_shr(rhs) {
+                return Self::from_fixnum_wrapping(n);
+            }
+        }
+        unsafe { self.call_with(">>", &[Self::from(rhs)]) }
+            .to_integer()
+            .expect("`>>` did not return an Integer")
+    }
+}
+
+impl ops::Neg for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        match self.to_fixnum().and_then(isize::checked_neg).filter(|&n| n << 1 >> 1 == n) {
+            Some(n) => Self::from_fixnum_wrapping(n),
+            None => unsafe { Self::from_raw(ruby::rb_big_uminus(self.raw())) },
+        }
+    }
+}
+
+// `rb_big_plus`/`_minus`/`_mul`/`_div`/`_modulo` all require their *first*
+// argument to already be a genuine `T_BIGNUM`, unlike the logical ops above
+// (which can freely swap operands since AND/OR/XOR are commutative). `self`
+// or `rhs` alone failing the fixnum fast path does not mean `self` is that
+// bignum, e.g. `Integer::from(1) + <a real Bignum>` has `self` still a
+// Fixnum. So once the fast path doesn't apply, fall back to a real method
+// call the same way `pow_mod`/`gcd`/`lcm` do rather than guessing an operand
+// order; this also gets Ruby's own `ZeroDivisionError` for free on `/`/`%`
+// instead of handing a non-Bignum `VALUE` to a function that assumes one.
+//
+// The fast path (here and in `Neg`/`Shl` above) also only applies when the
+// `isize` result still fits in a fixnum, which is one bit narrower than
+// `isize`; `from_fixnum_wrapping` would otherwise wrap it.
+impl ops::Add for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        if let (Some(a), Some(b)) = (self.to_fixnum(), rhs.to_fixnum()) {
+            if let Some(n) = a.checked_add(b).filter(|&n| n << 1 >> 1 == n) {
+                return Self::from_fixnum_wrapping(n);
+            }
+        }
+        unsafe { self.call_with("+", &[rhs]) }
+            .to_integer()
+            .expect("`+` did not return an Integer")
+    }
+}
+
+impl ops::Sub for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        if let (Some(a), Some(b)) = (self.to_fixnum(), rhs.to_fixnum()) {
+            if let Some(n) = a.checked_sub(b).filter(|&n| n << 1 >> 1 == n) {
+                return Self::from_fixnum_wrapping(n);
+            }
+        }
+        unsafe { self.call_with("-", &[rhs]) }
+            .to_integer()
+            .expect("`-` did not return an Integer")
+    }
+}
+
+impl ops::Mul for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        if let (Some(a), Some(b)) = (self.to_fixnum(), rhs.to_fixnum()) {
+            if let Some(n) = a.checked_mul(b).filter(|&n| n << 1 >> 1 == n) {
+                return Self::from_fixnum_wrapping(n);
+            }
+        }
+        unsafe { self.call_with("*", &[rhs]) }
+            .to_integer()
+            .expect("`*` did not return an Integer")
+    }
+}
+
+// Ruby's `/` floors toward negative infinity rather than truncating toward
+// zero like Rust's, so the fixnum fast path has to re-derive the same
+// adjustment `rb_big_div` applies instead of reusing `isize`'s own operator.
+impl ops::Div for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        // `ops::Div` can't be `unsafe fn`, so a zero `rhs` has to be turned
+        // into a clean Rust panic here rather than reaching `call_with` and
+        // letting Ruby's `ZeroDivisionError` longjmp through this safe
+        // frame, the same way `to_integer_floor`'s `_checked_to_integer`
+        // asserts finiteness before `rb_dbl2big`.
+        assert!(!rhs.is_zero(), "divided by 0");
+        if let (Some(a), Some(b)) = (self.to_fixnum(), rhs.to_fixnum()) {
+            if let Some(q) = a.checked_div(b).filter(|&q| q << 1 >> 1 == q) {
+                let r = a % b;
+                let q = if r != 0 && (r < 0) != (b < 0) { q - 1 } else { q };
+                return Self::from_fixnum_wrapping(q);
+            }
+        }
+        unsafe { self.call_with("/", &[rhs]) }
+            .to_integer()
+            .expect("`/` did not return an Integer")
+    }
+}
+
+// Ruby's `%` takes the sign of the divisor, matching `rb_big_modulo`, rather
+// than the sign of the dividend like Rust's `%`.
+impl ops::Rem for Integer {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: Self) -> Self {
+        // Same reason as `ops::Div` above: turn a zero `rhs` into a panic
+        // instead of letting `ZeroDivisionError` longjmp through this safe
+        // trait method.
+        assert!(!rhs.is_zero(), "divided by 0");
+        if let (Some(a), Some(b)) = (self.to_fixnum(), rhs.to_fixnum()) {
+            if let Some(r) = a.checked_rem(b) {
+                let r = if r != 0 && (r < 0) != (b < 0) { r + b } else { r };
+                return Self::from_fixnum_wrapping(r);
+            }
+        }
+        unsafe { self.call_with("%", &[rhs]) }
+            .to_integer()
+            .expect("`%` did not return an Integer")
+    }
+}
+
+// Lets users write `int + 1` or `1 + int` directly instead of having to
+// construct an `Integer` from the primitive first, mirroring `forward_cmp!`.
+macro_rules! forward_arith {
+    ($($t:ty)+) => { $(
+        impl ops::Add<$t> for Integer {
+            type Output = Integer;
+            #[inline]
+            fn add(self, rhs: $t) -> Integer { self + Integer::from(rhs) }
+        }
+        impl ops::Add<Integer> for $t {
+            type Output = Integer;
+            #[inline]
+            fn add(self, rhs: Integer) -> Integer { Integer::from(self) + rhs }
+        }
+
+        impl ops::Sub<$t> for Integer {
+            type Output = Integer;
+            #[inline]
+            fn sub(self, rhs: $t) -> Integer { self - Integer::from(rhs) }
+        }
+        impl ops::Sub<Integer> for $t {
+            type Output = Integer;
+            #[inline]
+            fn sub(self, rhs: Integer) -> Integer { Integer::from(self) - rhs }
+        }
+
+        impl ops::Mul<$t> for Integer {
+            type Output = Integer;
+            #[inline]
+            fn mul(self, rhs: $t) -> Integer { self * Integer::from(rhs) }
+        }
+        impl ops::Mul<Integer> for $t {
+            type Output = Integer;
+            #[inline]
+            fn mul(self, rhs: Integer) -> Integer { Integer::from(self) * rhs }
+        }
+
+        impl ops::Div<$t> for Integer {
+            type Output = Integer;
+            #[inline]
+            fn div(self, rhs: $t) -> Integer { self / Integer::from(rhs) }
+        }
+        impl ops::Div<Integer> for $t {
+            type Output = Integer;
+            #[inline]
+            fn div(self, rhs: Integer) -> Integer { Integer::from(self) / rhs }
+        }
+
+        impl ops::Rem<$t> for Integer {
+            type Output = Integer;
+            #[inline]
+            fn rem(self, rhs: $t) -> Integer { self % Integer::from(rhs) }
+        }
+        impl ops::Rem<Integer> for $t {
+            type Output = Integer;
+            #[inline]
+            fn rem(self, rhs: Integer) -> Integer { Integer::from(self) % rhs }
+        }
+    )+ }
+}
+
+forward_arith! {
+    usize u128 u64 u32 u16 u8
+    isize i128 i64 i32 i16 i8
+}
+
 impl fmt::Display for Integer {
     #[inline]
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -323,6 +580,15 @@ impl fmt::Display for Integer {
     }
 }
 
+impl FromStr for Integer {
+    type Err = AnyException;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_str_radix(s, 10)
+    }
+}
+
 impl Integer {
     #[inline]
     const unsafe fn _from_raw(raw: ruby::VALUE) -> Self {
@@ -559,7 +825,9 @@ impl Integer {
     /// raised.
     pub fn to_s_radix(self, radix: u32) -> Result<String> {
         unsafe {
-            crate::protected_no_panic(|| self.to_s_radix_unchecked(radix))
+            crate::protected::exception_only(
+                crate::protected_no_panic(|| self.to_s_radix_unchecked(radix)),
+            )
         }
     }
 
@@ -573,6 +841,56 @@ impl Integer {
         String::from_raw(ruby::rb_big2str(self.raw(), radix as _))
     }
 
+    /// Parses `s` as an `Integer` in the given `radix`, or an exception if
+    /// `radix` is invalid (for example `radix > 36`).
+    ///
+    /// For a nonzero `radix`, parsing is lenient in the same way as Ruby's
+    /// `String#to_i`: it stops at the first character that isn't a digit in
+    /// `radix`, and a string with no leading digits parses as `0` instead of
+    /// returning an exception. A `radix` of `0` detects the base from a
+    /// prefix and returns an exception for malformed input.
+    ///
+    /// This is the inverse of [`to_s_radix`](#method.to_s_radix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// # rosy::protected(|| {
+    /// use rosy::Integer;
+    ///
+    /// let int = Integer::from_str_radix("2a", 16).unwrap();
+    /// assert_eq!(int, 42);
+    /// # }).unwrap();
+    /// ```
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self> {
+        let cstr = std::ffi::CString::new(s)
+            .map_err(|_| AnyException::_runtime_error("string contains a nul byte"))?;
+        unsafe {
+            crate::protected::exception_only(
+                crate::protected_no_panic(|| Self::_from_cstr_radix_unchecked(&cstr, radix)),
+            )
+        }
+    }
+
+    /// Parses `s` as an `Integer` in the given `radix`.
+    ///
+    /// # Safety
+    ///
+    /// An exception will be raised if `radix` is invalid (for example
+    /// `radix > 36`). Malformed `s` is parsed leniently as described for
+    /// [`from_str_radix`](#method.from_str_radix).
+    #[inline]
+    pub unsafe fn from_str_radix_unchecked(s: &str, radix: u32) -> Self {
+        let cstr = std::ffi::CString::new(s).expect("string contains a nul byte");
+        Self::_from_cstr_radix_unchecked(&cstr, radix)
+    }
+
+    #[inline]
+    unsafe fn _from_cstr_radix_unchecked(cstr: &CString, radix: u32) -> Self {
+        Self::from_raw(ruby::rb_cstr2inum(cstr.as_ptr(), radix as c_int))
+    }
+
     /// Packs the contents of `self` into `buf` with the platform's native byte
     /// order.
     ///
@@ -676,6 +994,167 @@ impl Integer {
     pub fn can_represent<W: Word>(self) -> bool {
         self._can_represent::<W>().0
     }
+
+    /// Returns the absolute value of `self`.
+    #[inline]
+    pub fn abs(self) -> Self {
+        if self.is_negative() { -self } else { self }
+    }
+
+    /// Returns `-1`, `0`, or `1` depending on whether `self` is negative,
+    /// zero, or positive respectively.
+    #[inline]
+    pub fn signum(self) -> Self {
+        if self.is_zero() {
+            Self::zero()
+        } else if self.is_negative() {
+            Self::from_fixnum_wrapping(-1)
+        } else {
+            Self::from_fixnum_wrapping(1)
+        }
+    }
+
+    /// Returns the number of bits required to represent the magnitude of
+    /// `self`, not including a sign bit.
+    ///
+    /// This equals Ruby's `Integer#bit_length` for non-negative `self`; for
+    /// negative `self` Ruby's method measures the two's complement form
+    /// instead, so e.g. `-1` gives `0` there but `1` here.
+    #[inline]
+    pub fn bit_length(self) -> usize {
+        let mut nlz_bits = 0;
+        let size = unsafe { ruby::rb_absint_size(self.raw(), &mut nlz_bits) };
+        size * 8 - nlz_bits as usize
+    }
+
+    // Packs the magnitude of `self` into the fewest `u64` words that can hold
+    // it, for `count_ones`/`trailing_zeros` to scan; there's no direct Ruby
+    // API for either, but `pack` already exposes the raw words `rb_integer_pack`
+    // computes internally.
+    fn _magnitude_words(self) -> Vec<u64> {
+        let len = (self.bit_length() + 63) / 64;
+        let mut words = vec![0u64; len.max(1)];
+        self.abs().pack(&mut words);
+        words
+    }
+
+    /// Returns the number of `1` bits in the binary representation of the
+    /// magnitude of `self` (i.e. of `self.abs()`).
+    #[inline]
+    pub fn count_ones(self) -> u32 {
+        self._magnitude_words().iter().map(|w| w.count_ones()).sum()
+    }
+
+    /// Returns the number of trailing zero bits in the binary representation
+    /// of the magnitude of `self`, or `0` if `self` is zero.
+    #[inline]
+    pub fn trailing_zeros(self) -> u32 {
+        if self.is_zero() {
+            return 0;
+        }
+        let mut total = 0;
+        for word in self._magnitude_words() {
+            if word == 0 {
+                total += 64;
+            } else {
+                return total + word.trailing_zeros();
+            }
+        }
+        total
+    }
+
+    /// Returns `self` raised to the power of `exp`.
+    ///
+    /// # Safety
+    ///
+    /// Raises `ZeroDivisionError` if `self` is `0` and `exp` is negative, the
+    /// same as Ruby's own `Integer#**`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// # rosy::protected(|| unsafe {
+    /// use rosy::Integer;
+    ///
+    /// let base = Integer::from(2);
+    /// assert_eq!(base.pow(Integer::from(10)), 1024);
+    /// # }).unwrap();
+    /// ```
+    #[inline]
+    pub unsafe fn pow(self, exp: Self) -> Self {
+        // `rb_big_pow` requires `self` to already be a genuine `T_BIGNUM`,
+        // which the example above isn't (`2` is a Fixnum); go through a real
+        // method call the same way `pow_mod`/`gcd`/`lcm` do instead.
+        self.call_with("**", &[exp])
+            .to_integer()
+            .expect("`**` did not return an Integer")
+    }
+
+    /// Returns `self` raised to the power of `exp`, modulo `modulus`,
+    /// equivalent to Ruby's two-argument `Integer#pow`.
+    ///
+    /// There's no dedicated C entry point for the modular form, so this goes
+    /// through a regular method call the same way
+    /// [`gcd`](#method.gcd)/[`lcm`](#method.lcm) do.
+    ///
+    /// # Safety
+    ///
+    /// Raises `ArgumentError` if `exp` is negative, or `ZeroDivisionError` if
+    /// `modulus` is `0`, the same as Ruby's own `Integer#pow`.
+    #[inline]
+    pub unsafe fn pow_mod(self, exp: Self, modulus: Self) -> Self {
+        self.call_with("pow", &[exp, modulus])
+            .to_integer()
+            .expect("`pow` did not return an Integer")
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    #[inline]
+    pub fn gcd(self, other: Self) -> Self {
+        unsafe { self.call_with("gcd", &[other]) }
+            .to_integer()
+            .expect("`gcd` did not return an Integer")
+    }
+
+    /// Returns the least common multiple of `self` and `other`.
+    #[inline]
+    pub fn lcm(self, other: Self) -> Self {
+        unsafe { self.call_with("lcm", &[other]) }
+            .to_integer()
+            .expect("`lcm` did not return an Integer")
+    }
+
+    /// Returns the integer square root of `self`, or an error if `self` is
+    /// negative.
+    ///
+    /// This refines an initial overestimate via Newton's method
+    /// (`x = (x + self / x) / 2`), which converges monotonically down to the
+    /// true root, so it stays exact for values too large to convert to `f64`
+    /// without losing precision.
+    pub fn isqrt(self) -> Result<Self> {
+        if self.is_negative() {
+            return Err(AnyException::_runtime_error(
+                "isqrt requires a non-negative Integer",
+            ));
+        }
+        if self.is_zero() {
+            return Ok(Self::zero());
+        }
+
+        let mut x = Self::from_fixnum_wrapping(1) << ((self.bit_length() as u32 + 1) / 2);
+        loop {
+            let next = (x + self / x) / Self::from(2);
+            if next >= x {
+                break;
+            }
+            x = next;
+        }
+        while x * x > self {
+            x = x - Self::from_fixnum_wrapping(1);
+        }
+        Ok(x)
+    }
 }
 
 #[cfg(test)]
@@ -723,6 +1202,48 @@ mod tests {
         }).unwrap();
     }
 
+    #[test]
+    fn fixnum_bignum_promotion() {
+        crate::vm::init().unwrap();
+
+        crate::protected(|| unsafe {
+            // Each of these but the last has a Fixnum `self` whose *result*
+            // overflows into a Bignum, the exact case `rb_big_plus`/etc.
+            // cannot handle directly since they require `self` to already be
+            // a Bignum. The last goes the other way: a Bignum `self` shifted
+            // down to a Fixnum.
+            let one = Integer::from(1);
+            let max = Integer::from(i64::max_value());
+
+            assert_eq!(one + max, Integer::from(i64::max_value() as i128 + 1));
+            assert_eq!(-one - max, Integer::from(-(i64::max_value() as i128) - 1));
+            assert_eq!(
+                Integer::from(2) * max,
+                Integer::from(i64::max_value() as i128 * 2),
+            );
+            assert_eq!(one << 100u32, Integer::from(2).pow(Integer::from(100)));
+            assert_eq!(Integer::from(2).pow(Integer::from(100)) >> 100u32, one);
+        }).unwrap();
+    }
+
+    #[test]
+    fn pow_gcd_lcm_isqrt() {
+        crate::vm::init().unwrap();
+
+        crate::protected(|| unsafe {
+            assert_eq!(Integer::from(2).pow(Integer::from(10)), 1024);
+            assert_eq!(Integer::from(5).pow_mod(Integer::from(3), Integer::from(13)), 8);
+
+            assert_eq!(Integer::from(12).gcd(Integer::from(18)), 6);
+            assert_eq!(Integer::from(4).lcm(Integer::from(6)), 12);
+        }).unwrap();
+
+        assert_eq!(Integer::from(16).isqrt().unwrap(), 4);
+        assert_eq!(Integer::from(17).isqrt().unwrap(), 4);
+        assert_eq!(Integer::from(0).isqrt().unwrap(), 0);
+        assert!(Integer::from(-1).isqrt().is_err());
+    }
+
     #[test]
     fn bit_ops() {
         crate::vm::init().unwrap();
@@ -0,0 +1,176 @@
+//! `num-traits` integration, enabled via the `num-traits` feature.
+//!
+//! This lets downstream crates already written against `num_traits::Num`,
+//! `PrimInt`-style bounds, etc. accept a [`rosy::Integer`](super::Integer)
+//! without special-casing it.
+
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedRem, CheckedSub, FromPrimitive, Num, One,
+    Signed, ToPrimitive, Zero,
+};
+use crate::exception::AnyException;
+use super::Integer;
+
+impl Zero for Integer {
+    #[inline]
+    fn zero() -> Self {
+        Integer::zero()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        (*self).is_zero()
+    }
+}
+
+impl One for Integer {
+    #[inline]
+    fn one() -> Self {
+        Self::from_fixnum_wrapping(1)
+    }
+}
+
+impl Bounded for Integer {
+    // Ruby's `Integer` is arbitrary-precision, so there's no true minimum or
+    // maximum; these mirror `i128`, the widest primitive `Integer` already
+    // converts to/from natively, rather than Ruby's much narrower internal
+    // fixnum range, since a generic caller expecting `Bounded` wants
+    // headroom to hold every native integer, not an implementation detail.
+    #[inline]
+    fn min_value() -> Self {
+        Self::from(i128::min_value())
+    }
+
+    #[inline]
+    fn max_value() -> Self {
+        Self::from(i128::max_value())
+    }
+}
+
+impl Signed for Integer {
+    #[inline]
+    fn abs(&self) -> Self {
+        (*self).abs()
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        let diff = *self - *other;
+        if diff.is_negative() { Self::zero() } else { diff }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        (*self).signum()
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        (*self).is_positive()
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        (*self).is_negative()
+    }
+}
+
+impl Num for Integer {
+    type FromStrRadixErr = AnyException;
+
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, AnyException> {
+        Integer::from_str_radix(s, radix)
+    }
+}
+
+impl ToPrimitive for Integer {
+    #[inline]
+    fn to_i64(&self) -> Option<i64> {
+        self.to_value()
+    }
+
+    #[inline]
+    fn to_u64(&self) -> Option<u64> {
+        self.to_value()
+    }
+
+    #[inline]
+    fn to_i128(&self) -> Option<i128> {
+        self.to_value()
+    }
+
+    #[inline]
+    fn to_u128(&self) -> Option<u128> {
+        self.to_value()
+    }
+}
+
+impl FromPrimitive for Integer {
+    #[inline]
+    fn from_i64(n: i64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline]
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline]
+    fn from_i128(n: i128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+
+    #[inline]
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::from(n))
+    }
+}
+
+// Ruby integers never overflow, so apart from a zero divisor for
+// `CheckedDiv`/`CheckedRem` (checked up front, since `/` and `%` panic on
+// it) the only way these can fail is a raised Ruby exception (e.g.
+// `NoMemoryError`); the overflow-prone half of
+// "checked" arithmetic is really the conversion back to a fixed-width Rust
+// type, which `ToPrimitive`/`to_value` already cover.
+impl CheckedAdd for Integer {
+    #[inline]
+    fn checked_add(&self, rhs: &Self) -> Option<Self> {
+        unsafe { crate::protected_no_panic(|| *self + *rhs) }.ok()
+    }
+}
+
+impl CheckedSub for Integer {
+    #[inline]
+    fn checked_sub(&self, rhs: &Self) -> Option<Self> {
+        unsafe { crate::protected_no_panic(|| *self - *rhs) }.ok()
+    }
+}
+
+impl CheckedMul for Integer {
+    #[inline]
+    fn checked_mul(&self, rhs: &Self) -> Option<Self> {
+        unsafe { crate::protected_no_panic(|| *self * *rhs) }.ok()
+    }
+}
+
+impl CheckedDiv for Integer {
+    #[inline]
+    fn checked_div(&self, rhs: &Self) -> Option<Self> {
+        if (*rhs).is_zero() {
+            return None;
+        }
+        unsafe { crate::protected_no_panic(|| *self / *rhs) }.ok()
+    }
+}
+
+impl CheckedRem for Integer {
+    #[inline]
+    fn checked_rem(&self, rhs: &Self) -> Option<Self> {
+        if (*rhs).is_zero() {
+            return None;
+        }
+        unsafe { crate::protected_no_panic(|| *self % *rhs) }.ok()
+    }
+}
@@ -194,7 +194,9 @@ impl AnyObject {
     // monomorphization
     fn _call_super_with(args: &[AnyObject]) -> Result<AnyObject> {
         unsafe {
-            crate::protected_no_panic(|| Self::call_super_with_unchecked(args))
+            crate::protected::exception_only(
+                crate::protected_no_panic(|| Self::call_super_with_unchecked(args)),
+            )
         }
     }
 
@@ -269,6 +271,23 @@ impl AnyObject {
         self.is_true() != self.is_false()
     }
 
+    /// Returns `self` downcast to `T` if `self` is actually a `T`,
+    /// dispatching on `T::unique_id()` and `self`'s runtime `Ty` the same
+    /// way the concrete `to_*` methods below do.
+    ///
+    /// This is the generic counterpart of those methods: it works for any
+    /// `T: Object`, including object types defined outside this crate.
+    #[inline]
+    pub fn downcast<T: Object>(self) -> Option<T> {
+        T::cast(self)
+    }
+
+    /// Returns whether `self` could be [`downcast`](#method.downcast) to `T`.
+    #[inline]
+    pub fn is<T: Object>(self) -> bool {
+        T::cast(self).is_some()
+    }
+
     /// Returns the boolean value for `self`, if any.
     #[inline]
     pub fn to_bool(self) -> Option<bool> {
@@ -417,4 +436,16 @@ impl AnyObject {
             None
         }
     }
+
+    /// Updates `self` to the object's new location if the GC has moved it
+    /// during a `GC.compact` cycle.
+    ///
+    /// Call this for every `AnyObject` (or other `Object`-typed field)
+    /// stored inside a [`Rosy`](trait.Rosy.html) type's
+    /// [`compact`](trait.Rosy.html#method.compact) implementation; it is a
+    /// no-op if `self` was not moved.
+    #[inline]
+    pub fn update_location(&mut self) {
+        self.raw = unsafe { ruby::rb_gc_location(self.raw) };
+    }
 }
@@ -128,17 +128,24 @@ impl<R: Rosy> RosyObject<R> {
         unsafe extern "C" fn dsize<R: Rosy>(rosy: *const c_void) -> usize {
             (&*(rosy as *const R)).size()
         }
+        // Lets `R` update any `VALUE`s it stores via `AnyObject::update_location`
+        // after `GC.compact` has potentially relocated them.
+        unsafe extern "C" fn dcompact<R: Rosy>(rosy: *mut c_void) {
+            (&mut *(rosy as *mut R)).compact();
+        }
         &rb_data_type_t {
             wrap_struct_name: R::ID,
             function: rb_data_type_t_function {
                 dmark: Some(dmark::<R>),
                 dfree: Some(dfree::<R>),
                 dsize: Some(dsize::<R>),
-                reserved: [ptr::null_mut(); 2],
+                dcompact: Some(dcompact::<R>),
+                reserved: [ptr::null_mut(); 1],
             },
             parent: ptr::null(),
             data: ptr::null_mut(),
-            flags: ruby::RUBY_TYPED_FREE_IMMEDIATELY,
+            flags: ruby::RUBY_TYPED_FREE_IMMEDIATELY
+                | (ruby::RUBY_TYPED_WB_PROTECTED * R::WB_PROTECTED as ruby::VALUE),
         }
     }
 
@@ -0,0 +1,254 @@
+//! Calling into the VM while catching Ruby exceptions and non-local jumps.
+
+use std::{
+    os::raw::c_int,
+    panic::{self, AssertUnwindSafe},
+};
+use crate::{
+    exception::AnyException,
+    object::AnyObject,
+    prelude::*,
+    ruby,
+};
+
+// The tag Ruby uses internally for `rb_protect` to indicate that an
+// exception (as opposed to some other jump) was raised.
+const RAISE_TAG: c_int = 6;
+
+/// Calls `f`, catching any Ruby exception or non-local jump it raises.
+///
+/// A Rust panic in `f` is caught before it can unwind through the VM's C
+/// frames and is resumed once `rb_protect` has returned.
+///
+/// See [`protected_no_panic`](fn.protected_no_panic.html) for the version of
+/// this used internally that does not guard against panics, for cases where
+/// `f` is known to not panic.
+///
+/// # Examples
+///
+/// ```
+/// # rosy::vm::init().unwrap();
+/// use rosy::{Object, String};
+///
+/// let string = String::from("hello\r\n");
+///
+/// rosy::protected(|| unsafe {
+///     string.call("chomp!");
+/// }).unwrap();
+///
+/// assert_eq!(string.len(), 5);
+/// ```
+#[inline]
+pub fn protected<F, O>(f: F) -> Result<O, ProtectError>
+where
+    F: FnOnce() -> O,
+{
+    let mut output: Option<O> = None;
+    let mut panic_payload = None;
+    let mut f = Some(f);
+    let result = unsafe {
+        _protect(|| {
+            let f = f.take().expect("`protected` closure called twice");
+            match panic::catch_unwind(AssertUnwindSafe(f)) {
+                Ok(out) => output = Some(out),
+                Err(payload) => panic_payload = Some(payload),
+            }
+        })
+    };
+    if let Some(payload) = panic_payload {
+        panic::resume_unwind(payload);
+    }
+    result.map(|()| output.expect("`protected` closure never ran"))
+}
+
+/// Calls `f`, catching any Ruby exception or non-local jump it raises,
+/// without guarding against a Rust panic unwinding out of `f`.
+///
+/// # Safety
+///
+/// The caller must ensure that `f` cannot panic; if it does, the panic will
+/// attempt to unwind across the C frames set up by `rb_protect`, which is
+/// undefined behavior.
+#[inline]
+pub unsafe fn protected_no_panic<F, O>(f: F) -> Result<O, ProtectError>
+where
+    F: FnOnce() -> O,
+{
+    let mut output: Option<O> = None;
+    let mut f = Some(f);
+    let result = _protect(|| {
+        let f = f.take().expect("`protected_no_panic` closure called twice");
+        output = Some(f());
+    });
+    result.map(|()| output.expect("`protected_no_panic` closure never ran"))
+}
+
+// Monomorphization: every caller above funnels through this non-generic
+// core so only one copy of the `rb_protect` trampoline gets instantiated.
+unsafe fn _protect<F: FnMut()>(mut f: F) -> Result<(), ProtectError> {
+    extern "C" fn call<F: FnMut()>(data: ruby::VALUE) -> ruby::VALUE {
+        let f = data as *mut F;
+        unsafe { (*f)() };
+        crate::util::NIL_VALUE
+    }
+
+    let data = &mut f as *mut F as ruby::VALUE;
+    let mut state: c_int = 0;
+    ruby::rb_protect(call::<F>, data, &mut state);
+
+    if state == 0 {
+        Ok(())
+    } else if state == RAISE_TAG {
+        Err(ProtectError::Exception(AnyException::_take_current()))
+    } else {
+        let tag = JumpTag::_from_raw(state);
+        Err(ProtectError::Jump(tag))
+    }
+}
+
+/// The error returned by [`protected`](fn.protected.html) and
+/// [`protected_no_panic`](fn.protected_no_panic.html): either a raised Ruby
+/// exception, or a non-local jump (`throw`, `break`, `return`, `retry`,
+/// `redo`, or `next`) that unwound through the protected closure.
+#[derive(Debug)]
+pub enum ProtectError {
+    /// A Ruby exception was raised.
+    Exception(AnyException),
+    /// A non-local jump unwound through the protected closure instead of an
+    /// exception being raised.
+    Jump(JumpTag),
+}
+
+impl ProtectError {
+    /// Returns the exception that was raised, if any.
+    #[inline]
+    pub fn exception(self) -> Option<AnyException> {
+        match self {
+            ProtectError::Exception(exc) => Some(exc),
+            ProtectError::Jump(_) => None,
+        }
+    }
+
+    /// Returns the non-local jump that was caught, if any.
+    #[inline]
+    pub fn jump(self) -> Option<JumpTag> {
+        match self {
+            ProtectError::Exception(_) => None,
+            ProtectError::Jump(tag) => Some(tag),
+        }
+    }
+
+    /// Re-raises `self` into the VM: re-raises the exception, or forwards
+    /// the non-local jump back up the Ruby call stack via `rb_jump_tag`.
+    ///
+    /// This is useful for a Rust callback embedded in Ruby that does not
+    /// know how to handle a given jump and needs to forward it rather than
+    /// swallow it.
+    ///
+    /// # Safety
+    ///
+    /// This performs a non-local jump (`longjmp` or `rb_exc_raise`) and
+    /// therefore never returns; Rust destructors between the caller and the
+    /// nearest enclosing `rb_protect` frame will not run.
+    #[inline]
+    pub unsafe fn reraise(self) -> ! {
+        match self {
+            ProtectError::Exception(exc) => exc.raise(),
+            ProtectError::Jump(tag) => tag.reraise(),
+        }
+    }
+}
+
+impl From<AnyException> for ProtectError {
+    #[inline]
+    fn from(exc: AnyException) -> Self {
+        ProtectError::Exception(exc)
+    }
+}
+
+// Narrows a `ProtectError` down to a plain `AnyException`, for call sites
+// that have no way to represent a non-local jump in their own return type
+// (because the protected closure never runs a block that could `break`,
+// `return`, etc. out of it). Any such jump is forwarded back into the VM
+// instead of being silently dropped.
+#[inline]
+pub(crate) unsafe fn exception_only<O>(result: Result<O, ProtectError>) -> crate::Result<O> {
+    result.map_err(|err| match err {
+        ProtectError::Exception(exc) => exc,
+        ProtectError::Jump(tag) => tag.reraise(),
+    })
+}
+
+/// The kind of non-local jump reported by Ruby's `rb_protect` through its
+/// `state` out-parameter, for any `state` other than `0` (no error) or `6`
+/// (an exception was raised; see [`ProtectError::Exception`][exc]).
+///
+/// [exc]: enum.ProtectError.html#variant.Exception
+#[derive(Debug)]
+pub enum JumpTag {
+    /// `RUBY_TAG_RETURN`: a `return` was executed.
+    Return,
+    /// `RUBY_TAG_BREAK`: a `break` was executed.
+    Break,
+    /// `RUBY_TAG_NEXT`: a `next` was executed.
+    Next,
+    /// `RUBY_TAG_RETRY`: a `retry` was executed.
+    Retry,
+    /// `RUBY_TAG_REDO`: a `redo` was executed.
+    Redo,
+    /// `RUBY_TAG_THROW`: a `throw` was executed, carrying the thrown value.
+    Throw(AnyObject),
+    /// `RUBY_TAG_FATAL`: a fatal error occurred, typically during VM
+    /// shutdown.
+    Fatal,
+}
+
+impl JumpTag {
+    // Maps a nonzero, non-`RAISE_TAG` `rb_protect` state to its `JumpTag`.
+    //
+    // For `RUBY_TAG_THROW`, `rb_errinfo` also holds the thrown value, the
+    // same way it holds the exception for `RUBY_TAG_RAISE`.
+    unsafe fn _from_raw(state: c_int) -> Self {
+        use JumpTag::*;
+        match state {
+            1 => Return,
+            2 => Break,
+            3 => Next,
+            4 => Retry,
+            5 => Redo,
+            7 => Throw(AnyException::_take_current().into_any_object()),
+            8 => Fatal,
+            _ => unreachable!("unknown Ruby jump tag: {}", state),
+        }
+    }
+
+    #[inline]
+    fn _raw(&self) -> c_int {
+        use JumpTag::*;
+        match self {
+            Return => 1,
+            Break => 2,
+            Next => 3,
+            Retry => 4,
+            Redo => 5,
+            Throw(_) => 7,
+            Fatal => 8,
+        }
+    }
+
+    /// Re-raises `self` by forwarding it back into the VM via
+    /// `rb_jump_tag`, continuing the non-local jump it represents.
+    ///
+    /// # Safety
+    ///
+    /// This performs a non-local jump and therefore never returns; Rust
+    /// destructors between the caller and the nearest enclosing
+    /// `rb_protect` frame will not run.
+    #[inline]
+    pub unsafe fn reraise(self) -> ! {
+        if let JumpTag::Throw(value) = self {
+            ruby::rb_set_errinfo(value.raw());
+        }
+        ruby::rb_jump_tag(self._raw())
+    }
+}
@@ -0,0 +1,82 @@
+//! The [`Rosy`](trait.Rosy.html) trait for wrapping Rust data in a Ruby
+//! object.
+
+use std::os::raw::c_char;
+use crate::{
+    object::RosyObject,
+    prelude::*,
+};
+
+/// A type whose instances can be wrapped in a Ruby object (see
+/// [`RosyObject`](object/struct.RosyObject.html)) and passed back and forth
+/// across the Ruby/Rust boundary.
+pub trait Rosy: Sized + 'static {
+    /// A nul-terminated, statically-unique name for `Self`, used as the
+    /// `wrap_struct_name` of the underlying `rb_data_type_t`.
+    const ID: *const c_char;
+
+    /// Returns an identifier used to disambiguate `Self` from other `Rosy`
+    /// types when downcasting an `AnyObject`.
+    ///
+    /// The default implementation uses the address of [`ID`](#associatedconstant.ID),
+    /// which is unique per `Self` since every monomorphization gets its own
+    /// `ID`.
+    #[inline]
+    fn unique_object_id() -> Option<u128> {
+        Some(Self::ID as usize as u128)
+    }
+
+    /// Returns the Ruby class instances of `Self` are wrapped by.
+    fn class() -> Class<RosyObject<Self>>;
+
+    /// Attempts to view `obj` as a `RosyObject<Self>`.
+    #[inline]
+    fn cast<A: Object>(obj: A) -> Option<RosyObject<Self>> {
+        if obj.class().inherits(Self::class().into_any_class()) {
+            Some(unsafe { RosyObject::from_raw(obj.raw()) })
+        } else {
+            None
+        }
+    }
+
+    /// Marks any Ruby objects reachable from `self` so the GC doesn't
+    /// collect them.
+    ///
+    /// The default implementation marks nothing, which is correct as long
+    /// as `self` holds no Ruby objects.
+    #[inline]
+    fn mark(&mut self) {}
+
+    /// Updates any Ruby objects reachable from `self` to their new location
+    /// after a `GC.compact` cycle has potentially moved them, typically by
+    /// calling [`AnyObject::update_location`](object/struct.AnyObject.html#method.update_location)
+    /// on each one.
+    ///
+    /// The default implementation does nothing, which is correct under the
+    /// same condition as [`mark`](#method.mark): `self` holds no Ruby
+    /// objects directly.
+    #[inline]
+    fn compact(&mut self) {}
+
+    /// Whether instances of `Self` are write-barrier protected
+    /// (`RUBY_TYPED_WB_PROTECTED`).
+    ///
+    /// Opting in by setting this to `true` lets the generational/incremental
+    /// GC skip re-scanning `self` on every minor collection, but requires
+    /// calling `rb_gc_writebarrier` after every mutation that stores a new
+    /// Ruby object in `self`. The default of `false` is always safe.
+    const WB_PROTECTED: bool = false;
+
+    /// Returns an approximation of how many bytes `self` occupies, used by
+    /// the GC to schedule collections.
+    #[inline]
+    fn size(&self) -> usize {
+        std::mem::size_of::<Self>()
+    }
+
+    /// Frees `self`.
+    ///
+    /// The default implementation simply drops it.
+    #[inline]
+    fn free(self) {}
+}
@@ -0,0 +1,42 @@
+use super::prelude::*;
+
+extern "C" {
+    // void rb_define_method(VALUE klass, const char *name, VALUE (*func)(ANYARGS), int argc)
+    pub fn rb_define_method(
+        klass: VALUE,
+        name: *const c_char,
+        func: unsafe extern "C" fn(c_int, *const VALUE, VALUE) -> VALUE,
+        argc: c_int,
+    );
+    // void rb_define_private_method(VALUE klass, const char *name, VALUE (*func)(ANYARGS), int argc)
+    pub fn rb_define_private_method(
+        klass: VALUE,
+        name: *const c_char,
+        func: unsafe extern "C" fn(c_int, *const VALUE, VALUE) -> VALUE,
+        argc: c_int,
+    );
+    // void rb_define_protected_method(VALUE klass, const char *name, VALUE (*func)(ANYARGS), int argc)
+    pub fn rb_define_protected_method(
+        klass: VALUE,
+        name: *const c_char,
+        func: unsafe extern "C" fn(c_int, *const VALUE, VALUE) -> VALUE,
+        argc: c_int,
+    );
+    // void rb_define_module_function(VALUE module, const char *name, VALUE (*func)(ANYARGS), int argc)
+    pub fn rb_define_module_function(
+        module: VALUE,
+        name: *const c_char,
+        func: unsafe extern "C" fn(c_int, *const VALUE, VALUE) -> VALUE,
+        argc: c_int,
+    );
+
+    // ID rb_frame_this_func(void)
+    pub fn rb_frame_this_func() -> ID;
+    // const char *rb_id2name(ID id)
+    pub fn rb_id2name(id: ID) -> *const c_char;
+
+    // VALUE rb_ivar_get(VALUE obj, ID id)
+    pub fn rb_ivar_get(obj: VALUE, id: ID) -> VALUE;
+    // VALUE rb_ivar_set(VALUE obj, ID id, VALUE val)
+    pub fn rb_ivar_set(obj: VALUE, id: ID, val: VALUE) -> VALUE;
+}
@@ -0,0 +1,16 @@
+use super::prelude::*;
+
+extern "C" {
+    // VALUE rb_mod_ancestors(VALUE mod)
+    pub fn rb_mod_ancestors(module: VALUE) -> VALUE;
+    // VALUE rb_class_inherited_p(VALUE mod, VALUE arg)
+    pub fn rb_class_inherited_p(module: VALUE, arg: VALUE) -> VALUE;
+
+    // void rb_alias(VALUE module, ID new, ID old)
+    pub fn rb_alias(module: VALUE, new: ID, old: ID);
+
+    // VALUE rb_obj_frozen_p(VALUE obj)
+    pub fn rb_obj_frozen_p(obj: VALUE) -> VALUE;
+    // VALUE rb_obj_freeze(VALUE obj)
+    pub fn rb_obj_freeze(obj: VALUE) -> VALUE;
+}
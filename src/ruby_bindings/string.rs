@@ -104,6 +104,26 @@ pub struct rb_encoding {
     pub flags: c_uint,
 }
 
+impl rb_encoding {
+    // Indices for the three encodings `String` checks against most often,
+    // read straight from the matching `rb_*_encindex` functions instead of
+    // resolving an `rb_encoding*` first.
+    #[inline]
+    pub fn ascii_8bit_index() -> c_int {
+        unsafe { rb_ascii8bit_encindex() }
+    }
+
+    #[inline]
+    pub fn utf8_index() -> c_int {
+        unsafe { rb_utf8_encindex() }
+    }
+
+    #[inline]
+    pub fn us_ascii_index() -> c_int {
+        unsafe { rb_usascii_encindex() }
+    }
+}
+
 pub const STR_TMPLOCK: VALUE = fl_type::FL_USER_7;
 
 pub mod rstring_flags {
@@ -119,6 +139,17 @@ pub mod rstring_flags {
     pub const FSTR: usize = FL_USER_17;
 }
 
+// Mirrors the C `ruby_coderange_type` enum, cached in a string's flags by
+// `ENC_CODERANGE_SET` and read back out by `rb_enc_str_coderange`.
+pub mod coderange_flags {
+    use super::fl_type::*;
+
+    pub const UNKNOWN: usize = 0;
+    pub const SEVEN_BIT: usize = FL_USER_8;
+    pub const VALID: usize = FL_USER_9;
+    pub const BROKEN: usize = FL_USER_8 | FL_USER_9;
+}
+
 extern "C" {
     // VALUE rb_external_str_new_with_enc(const char *ptr, long len, rb_encoding *eenc)
     pub fn rb_external_str_new_with_enc(ptr: *const c_char, len: c_long, enc: *mut rb_encoding) -> VALUE;
@@ -127,6 +158,10 @@ extern "C" {
     pub fn rb_str_cat(str: VALUE, ptr: *const c_char, len: c_long) -> VALUE;
     // int rb_str_cmp(VALUE str1, VALUE str2)
     pub fn rb_str_cmp(str1: VALUE, str2: VALUE) -> c_int;
+    // VALUE rb_str_conv_enc(VALUE str, rb_encoding *from, rb_encoding *to)
+    pub fn rb_str_conv_enc(str: VALUE, from: *mut rb_encoding, to: *mut rb_encoding) -> VALUE;
+    // int rb_enc_str_coderange(VALUE str)
+    pub fn rb_enc_str_coderange(str: VALUE) -> c_int;
     // VALUE rb_str_dup(VALUE str)
     pub fn rb_str_dup(str: VALUE) -> VALUE;
     // VALUE rb_str_ellipsize(VALUE str, long len)
@@ -135,6 +170,14 @@ extern "C" {
     pub fn rb_str_equal(str1: VALUE, str2: VALUE) -> VALUE;
     // VALUE rb_str_new(const char *ptr, long len)
     pub fn rb_str_new(ptr: *const c_char, len: c_long) -> VALUE;
+    // VALUE rb_str_new_frozen(VALUE orig)
+    pub fn rb_str_new_frozen(orig: VALUE) -> VALUE;
+    // VALUE rb_str_new_shared(VALUE orig)
+    pub fn rb_str_new_shared(orig: VALUE) -> VALUE;
+    // VALUE rb_str_resize(VALUE str, long len)
+    pub fn rb_str_resize(str: VALUE, len: c_long) -> VALUE;
+    // VALUE rb_str_to_interned_str(VALUE orig)
+    pub fn rb_str_to_interned_str(orig: VALUE) -> VALUE;
     // VALUE rb_utf8_str_new(const char *ptr, long len)
     pub fn rb_utf8_str_new(ptr: *const c_char, len: c_long) -> VALUE;
     // long rb_str_strlen(VALUE str)
@@ -153,6 +196,12 @@ extern "C" {
     // rb_encoding * rb_default_internal_encoding(void)
     pub fn rb_default_internal_encoding() -> *mut rb_encoding;
 
+    // int rb_enc_associate_index(VALUE obj, int idx)
+    pub fn rb_enc_associate_index(obj: VALUE, idx: c_int) -> c_int;
+    // unsigned int rb_enc_codepoint_len(const char *p, const char *e, int *len_p, rb_encoding *enc)
+    pub fn rb_enc_codepoint_len(p: *const c_char, e: *const c_char, len_p: *mut c_int, enc: *mut rb_encoding) -> c_uint;
+    // rb_encoding * rb_enc_compatible(VALUE str1, VALUE str2)
+    pub fn rb_enc_compatible(str1: VALUE, str2: VALUE) -> *mut rb_encoding;
     // int rb_enc_find_index(const char *name)
     pub fn rb_enc_find_index(name: *const c_char) -> c_int;
     // VALUE rb_enc_from_encoding(rb_encoding *encoding)
@@ -0,0 +1,69 @@
+use std::os::raw::{c_char, c_void};
+use super::{prelude::*, RBasic};
+
+/// `struct RTypedData` (see `ruby/internal/core/rtypeddata.h`): the layout of
+/// an object allocated via `rb_data_typed_object_wrap`.
+#[repr(C)]
+pub struct RTypedData {
+    pub basic: RBasic,
+    pub type_: *const rb_data_type_t,
+    pub typed_flag: VALUE,
+    pub data: *mut c_void,
+}
+
+/// `struct rb_data_type_struct` (see `ruby/internal/core/rtypeddata.h`).
+#[repr(C)]
+pub struct rb_data_type_t {
+    pub wrap_struct_name: *const c_char,
+    pub function: rb_data_type_t_function,
+    pub parent: *const rb_data_type_t,
+    pub data: *mut c_void,
+    pub flags: VALUE,
+}
+
+/// The `function` member of [`rb_data_type_t`](struct.rb_data_type_t.html).
+#[repr(C)]
+pub struct rb_data_type_t_function {
+    pub dmark: Option<unsafe extern "C" fn(*mut c_void)>,
+    pub dfree: Option<unsafe extern "C" fn(*mut c_void)>,
+    pub dsize: Option<unsafe extern "C" fn(*const c_void) -> usize>,
+    /// Called during `GC.compact` so wrapped data can update any `VALUE`s it
+    /// stores via `rb_gc_location`, since the GC may have relocated them.
+    pub dcompact: Option<unsafe extern "C" fn(*mut c_void)>,
+    pub reserved: [*mut c_void; 1],
+}
+
+/// `RUBY_TYPED_FREE_IMMEDIATELY` (see `enum rb_typeddata_flags`): `dfree` may
+/// run immediately instead of being deferred to a finalizer pass.
+pub const RUBY_TYPED_FREE_IMMEDIATELY: VALUE = 1;
+/// `RUBY_TYPED_WB_PROTECTED`: the wrapped object participates in write
+/// barriers, letting the generational/incremental GC skip re-scanning it
+/// unless `rb_gc_writebarrier` is called after it's mutated.
+///
+/// This must line up with `RUBY_FL_WB_PROTECTED` from `enum ruby_fl_type`
+/// (see `ruby/internal/fl_type.h`), which reserves bits 0-4 of every
+/// `RBasic::flags` for the `T_MASK` object type tag; the write-barrier bit
+/// sits just above that, at bit 5.
+pub const RUBY_TYPED_WB_PROTECTED: VALUE = 1 << 5;
+
+#[cfg(test)]
+mod assertions {
+    use static_assertions::const_assert_eq;
+    use super::RUBY_TYPED_WB_PROTECTED;
+
+    // Pins the flag against `ruby_fl_type::RUBY_FL_WB_PROTECTED` so it can't
+    // silently drift out of `T_MASK`'s bits 0-4 again.
+    const_assert_eq!(RUBY_TYPED_WB_PROTECTED, 1 << 5);
+}
+
+extern "C" {
+    // VALUE rb_data_typed_object_wrap(VALUE klass, void *datap, const rb_data_type_t *type)
+    pub fn rb_data_typed_object_wrap(
+        klass: VALUE,
+        datap: *mut c_void,
+        type_: *const rb_data_type_t,
+    ) -> VALUE;
+
+    // VALUE rb_gc_location(VALUE obj)
+    pub fn rb_gc_location(obj: VALUE) -> VALUE;
+}
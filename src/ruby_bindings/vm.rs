@@ -0,0 +1,47 @@
+use std::os::raw::{c_long, c_void};
+use super::prelude::*;
+
+/// An opaque handle to the debug inspector context passed to a
+/// [`rb_debug_inspector_func_t`](type.rb_debug_inspector_func_t.html)
+/// callback, valid only for the duration of that call.
+#[repr(C)]
+pub struct rb_debug_inspector_t(());
+
+/// VALUE (*rb_debug_inspector_func_t)(const rb_debug_inspector_t *dc, void *data)
+pub type rb_debug_inspector_func_t =
+    unsafe extern "C" fn(*const rb_debug_inspector_t, *mut c_void) -> VALUE;
+
+extern "C" {
+    // VALUE rb_protect(VALUE (*proc)(VALUE), VALUE data, int *state)
+    pub fn rb_protect(
+        proc: extern "C" fn(VALUE) -> VALUE,
+        data: VALUE,
+        state: *mut c_int,
+    ) -> VALUE;
+    // void rb_jump_tag(int tag)
+    pub fn rb_jump_tag(tag: c_int) -> !;
+
+    // VALUE rb_errinfo(void)
+    pub fn rb_errinfo() -> VALUE;
+    // void rb_set_errinfo(VALUE err)
+    pub fn rb_set_errinfo(err: VALUE);
+
+    // VALUE rb_exc_raise(VALUE mesg)
+    pub fn rb_exc_raise(mesg: VALUE) -> !;
+
+    // VALUE rb_make_backtrace(void)
+    pub fn rb_make_backtrace() -> VALUE;
+
+    // VALUE rb_debug_inspector_open(rb_debug_inspector_func_t func, void *data)
+    pub fn rb_debug_inspector_open(
+        func: rb_debug_inspector_func_t,
+        data: *mut c_void,
+    ) -> VALUE;
+    // VALUE rb_debug_inspector_backtrace_locations(const rb_debug_inspector_t *dc)
+    pub fn rb_debug_inspector_backtrace_locations(dc: *const rb_debug_inspector_t) -> VALUE;
+
+    // long rb_array_len(VALUE ary)
+    pub fn rb_array_len(ary: VALUE) -> c_long;
+    // VALUE rb_ary_entry(VALUE ary, long offset)
+    pub fn rb_ary_entry(ary: VALUE, offset: c_long) -> VALUE;
+}
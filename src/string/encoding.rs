@@ -0,0 +1,178 @@
+//! Ruby string encodings.
+
+use std::{
+    ffi::{CStr, CString, NulError},
+    fmt,
+    os::raw::c_int,
+};
+use crate::ruby;
+
+/// An encoding for a [`String`](struct.String.html).
+///
+/// Unlike most of rosy's wrapper types, `Encoding` doesn't wrap a Ruby
+/// `VALUE`; it's a thin `Copy` handle around the `rb_encoding` index the C
+/// API already tags every string with, so it can be produced, compared, and
+/// passed around without touching the VM's object space.
+#[derive(Clone, Copy)]
+pub struct Encoding(c_int);
+
+impl fmt::Debug for Encoding {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Encoding").field("name", &self.name()).finish()
+    }
+}
+
+impl PartialEq for Encoding {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for Encoding {}
+
+impl Encoding {
+    #[inline]
+    pub(crate) fn _from_index(index: c_int) -> Self {
+        Encoding(index)
+    }
+
+    #[inline]
+    pub(crate) fn _from_enc(enc: *mut ruby::rb_encoding) -> Self {
+        Encoding::_from_index(unsafe { ruby::rb_enc_to_index(enc) })
+    }
+
+    #[inline]
+    pub(crate) fn _index(self) -> c_int {
+        self.0
+    }
+
+    #[inline]
+    pub(crate) fn _enc(self) -> *mut ruby::rb_encoding {
+        unsafe { ruby::rb_enc_from_index(self.0) }
+    }
+
+    /// Returns the `ASCII-8BIT` (aliased as `BINARY`) encoding, Ruby's
+    /// "anything goes" encoding for raw bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::{String, Encoding};
+    ///
+    /// let bytes: &[u8] = &[b'a', b'z', 0, 255];
+    /// let string = String::from(bytes);
+    ///
+    /// assert_eq!(string.encoding(), Encoding::ascii_8bit());
+    /// ```
+    #[inline]
+    pub fn ascii_8bit() -> Encoding {
+        unsafe { Encoding::_from_enc(ruby::rb_ascii8bit_encoding()) }
+    }
+
+    /// Returns the `UTF-8` encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::Encoding;
+    ///
+    /// let utf8 = Encoding::find("UTF-8").unwrap();
+    /// assert_eq!(utf8, Encoding::utf8());
+    /// ```
+    #[inline]
+    pub fn utf8() -> Encoding {
+        unsafe { Encoding::_from_enc(ruby::rb_utf8_encoding()) }
+    }
+
+    /// Returns the `US-ASCII` encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::Encoding;
+    ///
+    /// let ascii = Encoding::find("US-ASCII").unwrap();
+    /// assert_eq!(ascii, Encoding::us_ascii());
+    /// ```
+    #[inline]
+    pub fn us_ascii() -> Encoding {
+        unsafe { Encoding::_from_enc(ruby::rb_usascii_encoding()) }
+    }
+
+    /// Looks up the encoding registered under `name` (for example
+    /// `"Shift_JIS"`), returning an error if it isn't registered or contains
+    /// an interior nul byte and so can't be passed to Ruby as a C string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::Encoding;
+    ///
+    /// let utf8 = Encoding::find("UTF-8").unwrap();
+    /// let ascii = Encoding::find("US-ASCII").unwrap();
+    ///
+    /// assert_ne!(utf8, ascii);
+    /// assert!(Encoding::find("Not-A-Real-Encoding").is_err());
+    /// ```
+    #[inline]
+    pub fn find(name: &str) -> Result<Encoding, EncodingLookupError> {
+        let name = CString::new(name)?;
+        let index = unsafe { ruby::rb_enc_find_index(name.as_ptr()) };
+        if index < 0 {
+            Err(EncodingLookupError::UnknownName)
+        } else {
+            Ok(Encoding::_from_index(index))
+        }
+    }
+
+    /// Returns the encoding's name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::Encoding;
+    ///
+    /// assert_eq!(Encoding::utf8().name().to_bytes(), b"UTF-8");
+    /// ```
+    #[inline]
+    pub fn name(self) -> &'static CStr {
+        unsafe { CStr::from_ptr((*self._enc()).name) }
+    }
+}
+
+/// The error returned by [`Encoding::find`](struct.Encoding.html#method.find)
+/// when `name` doesn't resolve to a registered encoding.
+#[derive(Debug)]
+pub enum EncodingLookupError {
+    /// No encoding is registered under the given name.
+    UnknownName,
+    /// The name contains an interior nul byte and can't be passed to Ruby
+    /// as a C string.
+    InvalidCStr(NulError),
+}
+
+impl fmt::Display for EncodingLookupError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodingLookupError::UnknownName => "unknown encoding name".fmt(f),
+            EncodingLookupError::InvalidCStr(error) => error.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EncodingLookupError {}
+
+impl From<NulError> for EncodingLookupError {
+    #[inline]
+    fn from(error: NulError) -> Self {
+        EncodingLookupError::InvalidCStr(error)
+    }
+}
@@ -4,10 +4,13 @@ use std::{
     borrow::Cow,
     cmp::Ordering,
     convert::TryFrom,
-    ffi::{CStr, CString},
+    ffi::{CStr, CString, OsStr},
     fmt,
+    io,
     iter::FromIterator,
+    ops::RangeBounds,
     os::raw::{c_int, c_long},
+    path::Path,
     str::Utf8Error,
     string,
 };
@@ -20,6 +23,144 @@ use crate::{
 mod encoding;
 pub use encoding::*;
 
+// Byte-pattern search backing `find`/`rfind`/`split`. Delegates to
+// `memchr::memmem` when the `memchr` feature is enabled, otherwise falls
+// back to a dependency-free windowed scan.
+mod search {
+    #[cfg(feature = "memchr")]
+    pub(super) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        memchr::memmem::find(haystack, needle)
+    }
+
+    #[cfg(not(feature = "memchr"))]
+    pub(super) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(0);
+        }
+        if needle.len() > haystack.len() {
+            return None;
+        }
+        (0..=haystack.len() - needle.len()).find(|&i| haystack[i..].starts_with(needle))
+    }
+
+    #[cfg(feature = "memchr")]
+    pub(super) fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        memchr::memmem::rfind(haystack, needle)
+    }
+
+    #[cfg(not(feature = "memchr"))]
+    pub(super) fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        if needle.is_empty() {
+            return Some(haystack.len());
+        }
+        if needle.len() > haystack.len() {
+            return None;
+        }
+        (needle.len()..=haystack.len())
+            .rev()
+            .find(|&x| haystack[..x].ends_with(needle))
+            .map(|x| x - needle.len())
+    }
+}
+
+/// A needle searchable for within a `String`'s bytes via
+/// [`find`](struct.String.html#method.find),
+/// [`rfind`](struct.String.html#method.rfind), and friends.
+///
+/// Implemented for `&str`, `&[u8]`, and `char`.
+pub trait Pattern<'a> {
+    /// Returns the UTF-8/raw bytes to search for.
+    fn into_search_bytes(self) -> Cow<'a, [u8]>;
+}
+
+impl<'a> Pattern<'a> for &'a str {
+    #[inline]
+    fn into_search_bytes(self) -> Cow<'a, [u8]> {
+        Cow::Borrowed(self.as_bytes())
+    }
+}
+
+impl<'a> Pattern<'a> for &'a [u8] {
+    #[inline]
+    fn into_search_bytes(self) -> Cow<'a, [u8]> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl Pattern<'static> for char {
+    #[inline]
+    fn into_search_bytes(self) -> Cow<'static, [u8]> {
+        Cow::Owned(self.encode_utf8(&mut [0; 4]).as_bytes().to_vec())
+    }
+}
+
+impl Encoding {
+    /// Returns the encoding that `a` and `b` could be combined under, or
+    /// `None` if they're incompatible.
+    ///
+    /// This is the same check the VM performs before concatenating or
+    /// comparing two strings, so it can be used to predict ahead of time
+    /// whether doing so would raise `Encoding::CompatibilityError`.
+    #[inline]
+    pub fn compatible<A: Object, B: Object>(a: A, b: B) -> Option<Encoding> {
+        let enc = unsafe { ruby::rb_enc_compatible(a.raw(), b.raw()) };
+        if enc.is_null() {
+            None
+        } else {
+            Some(Encoding::_from_enc(enc))
+        }
+    }
+
+    /// Returns the encoding Ruby tags filesystem paths with: arbitrary bytes
+    /// on Unix, or Windows' native (UTF-16-ish) representation.
+    #[inline]
+    pub fn filesystem() -> Encoding {
+        Encoding::_from_index(unsafe { ruby::rb_filesystem_encindex() })
+    }
+
+    /// Returns whether `self` is the filesystem encoding.
+    #[inline]
+    pub fn is_filesystem(self) -> bool {
+        self._index() == unsafe { ruby::rb_filesystem_encindex() }
+    }
+}
+
+/// The cached classification of a [`String`](struct.String.html)'s bytes
+/// with respect to its [`encoding`](struct.String.html#method.encoding).
+///
+/// Ruby caches this in the string's flags so that repeated validity checks
+/// via [`code_range`](struct.String.html#method.code_range) are
+/// allocation-free. Mutating methods such as
+/// [`push_str`](struct.String.html#method.push_str) reset the cache back to
+/// [`Unknown`](#variant.Unknown), forcing the next check to rescan.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CodeRange {
+    /// The bytes haven't been scanned for validity since the last time the
+    /// string's contents changed.
+    Unknown,
+    /// Every byte is 7-bit ASCII, regardless of encoding.
+    SevenBit,
+    /// The bytes are well-formed for the string's encoding, but not 7-bit
+    /// ASCII.
+    Valid,
+    /// The bytes contain a sequence that's invalid for the string's
+    /// encoding.
+    Broken,
+}
+
+impl CodeRange {
+    #[inline]
+    fn _from_raw(raw: c_int) -> Self {
+        use ruby::coderange_flags::*;
+        match raw as usize {
+            SEVEN_BIT => CodeRange::SevenBit,
+            VALID => CodeRange::Valid,
+            BROKEN => CodeRange::Broken,
+            _ => CodeRange::Unknown,
+        }
+    }
+}
+
 /// An instance of Ruby's `String` class.
 #[derive(Clone, Copy, Debug)]
 #[repr(transparent)]
@@ -257,6 +398,84 @@ impl Ord for String {
     }
 }
 
+/// Appends written bytes to `self` via `rb_str_cat`, the same primitive
+/// backing [`push_str`](struct.String.html#method.push_str).
+///
+/// This lets code serializing into a Ruby string (JSON, templated text,
+/// ...) use `write!` directly instead of building a `Vec<u8>` first and
+/// copying it over afterward.
+///
+/// Unlike `push_str`, writing to a frozen `String` doesn't raise a
+/// `FrozenError` into the Rust stack; it's reported as an
+/// `io::ErrorKind::Other` error instead, since an unguarded `longjmp`
+/// through a safe trait method would skip drops.
+///
+/// # Examples
+///
+/// ```
+/// # rosy::vm::init().unwrap();
+/// use std::io::Write;
+///
+/// let mut string = rosy::String::new();
+/// write!(string, "{}, {}!", "Hello", "world").unwrap();
+///
+/// assert_eq!(string, "Hello, world!");
+/// ```
+impl io::Write for String {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.is_frozen() {
+            return Err(io::Error::new(io::ErrorKind::Other, "can't modify frozen String"));
+        }
+        unsafe { ruby::rb_str_cat(self.raw(), buf.as_ptr() as *const _, buf.len() as _) };
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn write_all(&mut self, buf: &[u8]) -> io::Result<()> {
+        self.write(buf).map(drop)
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Appends written text to `self` via `rb_str_cat`, the same primitive
+/// backing [`push_str`](struct.String.html#method.push_str).
+///
+/// This is the `&str`-only counterpart to the [`io::Write`](#impl-Write)
+/// impl above, letting `write!`/`writeln!` target a Ruby string without
+/// going through `io::Result`.
+///
+/// Unlike `push_str`, writing to a frozen `String` doesn't raise a
+/// `FrozenError` into the Rust stack; it's reported as a `fmt::Error`
+/// instead, since an unguarded `longjmp` through a safe trait method would
+/// skip drops.
+///
+/// # Examples
+///
+/// ```
+/// # rosy::vm::init().unwrap();
+/// use std::fmt::Write;
+///
+/// let mut string = rosy::String::new();
+/// write!(string, "{}, {}!", "Hello", "world").unwrap();
+///
+/// assert_eq!(string, "Hello, world!");
+/// ```
+impl fmt::Write for String {
+    #[inline]
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        if self.is_frozen() {
+            return Err(fmt::Error);
+        }
+        unsafe { ruby::rb_str_cat(self.raw(), s.as_ptr() as *const _, s.len() as _) };
+        Ok(())
+    }
+}
+
 impl String {
     #[inline]
     pub(crate) fn rstring(self) -> *mut ruby::RString {
@@ -282,12 +501,48 @@ impl String {
         Self::with_capacity(0)
     }
 
-    /// Creates a new string with `capacity`.
+    /// Creates a new string with `capacity`, pre-allocating its buffer via
+    /// `rb_str_buf_new` so that building it up incrementally (for example
+    /// through the
+    /// [`std::io::Write`](https://doc.rust-lang.org/std/io/trait.Write.html)
+    /// impl) doesn't reallocate on every append.
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         unsafe { Self::from_raw(ruby::rb_str_buf_new(capacity as _)) }
     }
 
+    /// Decodes `v` as UTF-16 into a UTF-8-encoded Ruby string, returning an
+    /// error if it contains any unpaired surrogates.
+    ///
+    /// See [`from_utf16_lossy`](#method.from_utf16_lossy) for a variant that
+    /// substitutes `'\u{FFFD}'` for unpaired surrogates instead of failing.
+    pub fn from_utf16(v: &[u16]) -> Result<Self, FromUtf16Error> {
+        let string = Self::with_capacity(v.len());
+        unsafe {
+            for c in std::char::decode_utf16(v.iter().cloned()) {
+                match c {
+                    Ok(c) => string.push(c),
+                    Err(_) => return Err(FromUtf16Error(())),
+                }
+            }
+            string.force_encoding(Encoding::utf8());
+        }
+        Ok(string)
+    }
+
+    /// Decodes `v` as UTF-16 into a UTF-8-encoded Ruby string, substituting
+    /// `'\u{FFFD}'` (the replacement character) for unpaired surrogates.
+    pub fn from_utf16_lossy(v: &[u16]) -> Self {
+        let string = Self::with_capacity(v.len());
+        unsafe {
+            for c in std::char::decode_utf16(v.iter().cloned()) {
+                string.push(c.unwrap_or(std::char::REPLACEMENT_CHARACTER));
+            }
+            string.force_encoding(Encoding::utf8());
+        }
+        string
+    }
+
     /// Returns a new instance from `s` encoded as `enc`.
     ///
     /// # Safety
@@ -305,12 +560,146 @@ impl String {
         ))
     }
 
+    /// Returns a new instance built from `s`'s native byte representation,
+    /// tagged with the [filesystem encoding](struct.Encoding.html#method.filesystem).
+    ///
+    /// This lets a Rust extension hand a path it just produced (via
+    /// `std::fs` or similar) back to Ruby without forcing it through lossy
+    /// UTF-8 first.
+    #[cfg(unix)]
+    pub fn from_os_str(s: &OsStr) -> Self {
+        use std::os::unix::ffi::OsStrExt;
+        unsafe { Self::with_encoding(s.as_bytes(), Encoding::filesystem()) }
+    }
+
+    /// Returns a new instance built from `s`'s native byte representation,
+    /// tagged with the [filesystem encoding](struct.Encoding.html#method.filesystem).
+    ///
+    /// On Windows the path goes through `OsStr::to_string_lossy` first, so
+    /// any unpaired surrogate in it becomes U+FFFD; every other path keeps
+    /// its characters.
+    #[cfg(windows)]
+    pub fn from_os_str(s: &OsStr) -> Self {
+        let lossy = s.to_string_lossy();
+        unsafe { Self::with_encoding(lossy.as_bytes(), Encoding::filesystem()) }
+    }
+
+    /// Returns `self`'s bytes as an `OsStr`, interpreted as the platform's
+    /// native path representation: a direct byte slice on Unix.
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`as_bytes`](#method.as_bytes): the returned `Cow` may
+    /// borrow straight from `self`'s live buffer, so it's only valid as long
+    /// as that buffer isn't relocated or freed out from under it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use std::ffi::OsStr;
+    ///
+    /// let string = rosy::String::from("/tmp/example");
+    /// unsafe { assert_eq!(&*string.to_os_str(), OsStr::new("/tmp/example")) };
+    /// ```
+    #[cfg(unix)]
+    pub unsafe fn to_os_str(&self) -> Cow<'_, OsStr> {
+        use std::os::unix::ffi::OsStrExt;
+        Cow::Borrowed(OsStr::from_bytes(self.as_bytes()))
+    }
+
+    /// Returns `self`'s bytes as an `OsStr`.
+    ///
+    /// Without the `os_str_bytes` crate's WTF-8 decoding, this falls back to
+    /// UTF-8, which is lossless for paths Ruby itself produced on Windows
+    /// but would mangle a lone UTF-16 surrogate from elsewhere.
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`as_bytes`](#method.as_bytes): the returned `Cow` may
+    /// borrow straight from `self`'s live buffer, so it's only valid as long
+    /// as that buffer isn't relocated or freed out from under it.
+    #[cfg(windows)]
+    pub unsafe fn to_os_str(&self) -> Cow<'_, OsStr> {
+        match self.to_str() {
+            Ok(s) => Cow::Borrowed(OsStr::new(s)),
+            Err(_) => Cow::Owned(OsStr::new(&self.to_str_lossy()).to_os_string()),
+        }
+    }
+
+    /// Returns `self`'s bytes as a `Path`. See
+    /// [`to_os_str`](#method.to_os_str) for the encoding rules.
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`to_os_str`](#method.to_os_str).
+    #[inline]
+    pub unsafe fn to_path(&self) -> Cow<'_, Path> {
+        match self.to_os_str() {
+            Cow::Borrowed(s) => Cow::Borrowed(Path::new(s)),
+            Cow::Owned(s) => Cow::Owned(s.into()),
+        }
+    }
+
     /// Duplicates the contents of `self` into a new instance.
     #[inline]
     pub fn duplicate(self) -> Self {
         unsafe { Self::from_raw(ruby::rb_str_dup(self.raw())) }
     }
 
+    /// Returns a frozen copy of `self`, or `self` itself if it's already
+    /// frozen.
+    #[inline]
+    pub fn into_frozen(self) -> Self {
+        unsafe { Self::from_raw(ruby::rb_str_new_frozen(self.raw())) }
+    }
+
+    /// Returns a new instance that shares the backing buffer of `self`
+    /// without copying it, becoming copy-on-write the moment either instance
+    /// is mutated.
+    #[inline]
+    pub fn new_shared(self) -> Self {
+        unsafe { Self::from_raw(ruby::rb_str_new_shared(self.raw())) }
+    }
+
+    /// Returns the canonical frozen instance for `s`, deduplicating equal
+    /// contents into a single allocation across the process.
+    ///
+    /// Programs that create the same string key repeatedly (hash lookups,
+    /// symbol-like usage) pay allocation and GC costs on every copy;
+    /// interning gives the same savings as Ruby's `# frozen_string_literal`
+    /// magic comment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// let a = rosy::String::interned("cached key");
+    /// let b = rosy::String::interned("cached key");
+    ///
+    /// assert_eq!(a.raw(), b.raw());
+    /// ```
+    #[inline]
+    pub fn interned(s: &str) -> Self {
+        unsafe { Self::from_raw(ruby::rb_str_to_interned_str(Self::from(s).raw())) }
+    }
+
+    /// Returns the canonical frozen instance with the same contents as
+    /// `self`, deduplicating it into the same process-wide table used by
+    /// [`interned`](#method.interned).
+    #[inline]
+    pub fn to_interned(self) -> Self {
+        unsafe { Self::from_raw(ruby::rb_str_to_interned_str(self.raw())) }
+    }
+
+    /// Returns whether `self` is frozen and therefore can't be mutated
+    /// in-place (via [`push`](#method.push), [`push_str`](#method.push_str),
+    /// and similar methods) without Ruby raising a `FrozenError`.
+    #[inline]
+    pub fn is_frozen(self) -> bool {
+        unsafe { AnyObject::from_raw(ruby::rb_obj_frozen_p(self.raw())) }.is_true()
+    }
+
     /// Returns how the bytes of `self` are encoded.
     ///
     /// # Examples
@@ -332,6 +721,66 @@ impl String {
         ruby::rb_enc_associate_index(self.raw(), encoding._index());
     }
 
+    /// Returns a new instance with the bytes of `self` transcoded from its
+    /// current [`encoding`](#method.encoding) to `to`, or an exception if the
+    /// bytes can't be represented in `to`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::{String, Encoding};
+    ///
+    /// let utf8 = String::from("caf\u{e9}");
+    /// let latin1 = utf8.encode(Encoding::find("ISO-8859-1").unwrap()).unwrap();
+    ///
+    /// assert_eq!(latin1.encoding(), Encoding::find("ISO-8859-1").unwrap());
+    /// ```
+    #[inline]
+    pub fn encode(self, to: Encoding) -> crate::Result<Self> {
+        self.encode_from(self.encoding(), to)
+    }
+
+    /// Alias for [`encode`](#method.encode).
+    #[inline]
+    pub fn encode_to(self, to: Encoding) -> crate::Result<Self> {
+        self.encode(to)
+    }
+
+    /// Returns a new instance with the bytes of `self` transcoded from `from`
+    /// to `to`, or an exception if the bytes can't be represented in `to`.
+    ///
+    /// This is useful when `self`'s reported encoding doesn't match its
+    /// actual bytes, such as right after [`force_encoding`](#method.force_encoding).
+    ///
+    /// A failed conversion is recognized by the result not being tagged with
+    /// `to`, so it goes unreported when `self` is already tagged with `to`.
+    #[inline]
+    pub fn encode_from(self, from: Encoding, to: Encoding) -> crate::Result<Self> {
+        let result = unsafe {
+            crate::protected::exception_only(
+                crate::protected_no_panic(|| self.encode_from_unchecked(from, to)),
+            )
+        }?;
+        if from != to && result.encoding() != to {
+            return Err(crate::AnyException::_runtime_error("string could not be transcoded"));
+        }
+        Ok(result)
+    }
+
+    /// Returns a new instance with the bytes of `self` transcoded from `from`
+    /// to `to`.
+    ///
+    /// # Safety
+    ///
+    /// If the bytes of `self` are invalid for `from` or can't be represented
+    /// in `to`, no exception is raised: `self` itself is returned, still
+    /// tagged with its original encoding.
+    #[inline]
+    pub unsafe fn encode_from_unchecked(self, from: Encoding, to: Encoding) -> Self {
+        Self::from_raw(ruby::rb_str_conv_enc(self.raw(), from._enc(), to._enc()))
+    }
+
     /// A fast shortcut to `self.encoding().is_ascii_8bit()`.
     ///
     /// # Examples
@@ -347,6 +796,58 @@ impl String {
         self._enc_index_skip_ivar() == ruby::rb_encoding::ascii_8bit_index()
     }
 
+    /// Returns Ruby's cached classification of the validity of the bytes in
+    /// `self`, scanning them to populate the cache if it's currently
+    /// [`Unknown`](enum.CodeRange.html#variant.Unknown).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// use rosy::string::CodeRange;
+    ///
+    /// let ascii = rosy::String::from("hello");
+    /// assert_eq!(ascii.code_range(), CodeRange::SevenBit);
+    ///
+    /// let unicode = rosy::String::from("héllo");
+    /// assert_eq!(unicode.code_range(), CodeRange::Valid);
+    /// ```
+    #[inline]
+    pub fn code_range(self) -> CodeRange {
+        CodeRange::_from_raw(unsafe { ruby::rb_enc_str_coderange(self.raw()) })
+    }
+
+    /// A fast shortcut to `self.code_range() == CodeRange::SevenBit`.
+    #[inline]
+    pub fn is_ascii_only(self) -> bool {
+        self.code_range() == CodeRange::SevenBit
+    }
+
+    /// Returns whether the bytes in `self` are well-formed for its current
+    /// [`encoding`](#method.encoding).
+    #[inline]
+    pub fn is_valid_encoding(self) -> bool {
+        self.code_range() != CodeRange::Broken
+    }
+
+    /// Returns the encoding that `self` and `other` could be combined under,
+    /// or `None` if concatenating or comparing them would raise
+    /// `Encoding::CompatibilityError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # rosy::vm::init().unwrap();
+    /// let ascii = rosy::String::from("hello");
+    /// let utf8 = rosy::String::from("héllo");
+    ///
+    /// assert_eq!(ascii.is_compatible_with(utf8), Some(utf8.encoding()));
+    /// ```
+    #[inline]
+    pub fn is_compatible_with(self, other: String) -> Option<Encoding> {
+        Encoding::compatible(self, other)
+    }
+
     /// A fast shortcut to `self.encoding().is_utf8()`.
     ///
     /// # Examples
@@ -426,6 +927,90 @@ impl String {
         unsafe { self.as_bytes().iter().cloned().any(f) }
     }
 
+    /// Returns the byte offset of the first occurrence of `needle` in
+    /// `self`, or `None` if it doesn't occur.
+    ///
+    /// `needle` accepts a `&str`, `&[u8]`, or `char` via [`Pattern`]. This
+    /// operates directly on the underlying bytes without assuming a UTF-8
+    /// encoding, so the returned offset is only meaningful as a char
+    /// boundary when `self` is valid UTF-8; it's usable as a raw byte
+    /// offset across any of `self`'s [`encoding`](#method.encoding)s.
+    #[inline]
+    pub fn find<'a, P: Pattern<'a>>(self, needle: P) -> Option<usize> {
+        unsafe { search::find(self.as_bytes(), &needle.into_search_bytes()) }
+    }
+
+    /// Returns the byte offset of the last occurrence of `needle` in `self`,
+    /// or `None` if it doesn't occur.
+    #[inline]
+    pub fn rfind<'a, P: Pattern<'a>>(self, needle: P) -> Option<usize> {
+        unsafe { search::rfind(self.as_bytes(), &needle.into_search_bytes()) }
+    }
+
+    /// Returns whether `needle` occurs anywhere in `self`.
+    #[inline]
+    pub fn contains<'a, P: Pattern<'a>>(self, needle: P) -> bool {
+        self.find(needle).is_some()
+    }
+
+    /// Returns whether the bytes of `self` start with `needle`.
+    #[inline]
+    pub fn starts_with<'a, P: Pattern<'a>>(self, needle: P) -> bool {
+        unsafe { self.as_bytes().starts_with(&*needle.into_search_bytes()) }
+    }
+
+    /// Returns whether the bytes of `self` end with `needle`.
+    #[inline]
+    pub fn ends_with<'a, P: Pattern<'a>>(self, needle: P) -> bool {
+        unsafe { self.as_bytes().ends_with(&*needle.into_search_bytes()) }
+    }
+
+    /// Returns an iterator over the byte sub-slices of `self` separated by
+    /// `delim`.
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`as_bytes`](#method.as_bytes).
+    #[inline]
+    pub unsafe fn split<'a>(&'a self, delim: &'a [u8]) -> Split<'a> {
+        Split { rest: Some(self.as_bytes()), delim }
+    }
+
+    /// Returns an iterator over the `char`s of `self`, decoded one codepoint
+    /// at a time according to its actual [`encoding`](#method.encoding)
+    /// rather than assumed to be UTF-8.
+    ///
+    /// A codepoint that isn't a valid Unicode scalar value (possible with
+    /// malformed trailing bytes) is yielded as the replacement character
+    /// (`'\u{FFFD}'`) instead of panicking.
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`as_bytes`](#method.as_bytes).
+    #[inline]
+    pub unsafe fn chars(&self) -> Chars<'_> {
+        let bytes = self.as_bytes();
+        let ptr = bytes.as_ptr() as *const c_char;
+        Chars {
+            ptr,
+            end: ptr.add(bytes.len()),
+            enc: self.encoding()._enc(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the `char`s of `self` and their byte
+    /// offsets, decoded the same way as [`chars`](#method.chars).
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`chars`](#method.chars).
+    #[inline]
+    pub unsafe fn char_indices(&self) -> CharIndices<'_> {
+        let chars = self.chars();
+        CharIndices { start: chars.ptr, chars }
+    }
+
     /// Returns a reference to the underlying UTF-8 encoded string in `self`.
     ///
     /// # Safety
@@ -434,13 +1019,13 @@ impl String {
     /// characters pointed to by `self` are not changed through the VM or
     /// otherwise.
     ///
-    /// If Ruby believes that the underlying encoding is indeed UTF-8, then we
-    /// return the bytes directly without any further checking. However, if the
-    /// method `force_encoding` has been called on `self`, then we are
-    /// susceptible to getting invalid UTF-8 in a `str` instance, which is UB.
-    /// To force a check, one should call
-    /// [`str::from_utf8`](https://doc.rust-lang.org/std/str/fn.from_utf8.html)
-    /// on the result of [`as_bytes`](#method.as_bytes).
+    /// This first consults `self`'s cached [`code_range`](#method.code_range):
+    /// a `SevenBit` string is pure ASCII and therefore valid UTF-8 regardless
+    /// of its declared encoding, and a `Valid` string whose encoding is UTF-8
+    /// is already known-good, so both return the bytes directly without any
+    /// further checking. Any other case (an `Unknown` or `Broken` range, or a
+    /// `Valid` one under another encoding) falls back to a fresh
+    /// `std::str::from_utf8` scan.
     ///
     /// # Examples
     ///
@@ -452,10 +1037,11 @@ impl String {
     /// unsafe { assert_eq!(rb.to_str().unwrap(), rs) };
     /// ```
     pub unsafe fn to_str(&self) -> Result<&str, Utf8Error> {
-        if self.encoding_is_utf8() {
-            return Ok(self.to_str_unchecked());
+        match self.code_range() {
+            CodeRange::SevenBit => Ok(self.to_str_unchecked()),
+            CodeRange::Valid if self.encoding_is_utf8() => Ok(self.to_str_unchecked()),
+            _ => std::str::from_utf8(self.as_bytes()),
         }
-        std::str::from_utf8(self.as_bytes())
     }
 
     /// Returns the underlying string lossy-encoded as UTF-8. See
@@ -468,18 +1054,15 @@ impl String {
     /// to `self`, the length of `self` and the characters pointed to by `self`
     /// are not changed through the VM or otherwise.
     ///
-    /// If Ruby believes that the underlying encoding is indeed UTF-8, then we
-    /// return the bytes directly without any further checking. However, if the
-    /// method `force_encoding` has been called on `self`, then we are
-    /// susceptible to getting invalid UTF-8 in a `str` instance, which is UB.
-    /// To force a check, one should call
-    /// [`str::from_utf8`](https://doc.rust-lang.org/std/str/fn.from_utf8.html)
-    /// on the result of [`as_bytes`](#method.as_bytes).
+    /// This consults `self`'s cached [`code_range`](#method.code_range) the
+    /// same way [`to_str`](#method.to_str) does, skipping the scan entirely
+    /// for a `SevenBit` string or a `Valid` one whose encoding is UTF-8.
     pub unsafe fn to_str_lossy(&self) -> Cow<'_, str> {
-        if self.encoding_is_utf8() {
-            return Cow::Borrowed(self.to_str_unchecked());
+        match self.code_range() {
+            CodeRange::SevenBit => Cow::Borrowed(self.to_str_unchecked()),
+            CodeRange::Valid if self.encoding_is_utf8() => Cow::Borrowed(self.to_str_unchecked()),
+            _ => std::string::String::from_utf8_lossy(self.as_bytes()),
         }
-        std::string::String::from_utf8_lossy(self.as_bytes())
     }
 
     /// Returns a reference to the underlying bytes of `self` as if they were
@@ -583,8 +1166,9 @@ impl String {
     ///
     /// # Safety
     ///
-    /// The caller must ensure that `self` is not frozen or else a `FrozenError`
-    /// exception will be raised.
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
     #[inline]
     pub unsafe fn push(self, c: char) {
         self.push_str(c.encode_utf8(&mut [0; 4]))
@@ -592,15 +1176,178 @@ impl String {
 
     /// Concatenates `s` to `self`.
     ///
+    /// This resets [`code_range`](#method.code_range) back to
+    /// [`CodeRange::Unknown`](enum.CodeRange.html#variant.Unknown), since the
+    /// appended bytes haven't been scanned yet.
+    ///
     /// # Safety
     ///
-    /// The caller must ensure that `self` is not frozen or else a `FrozenError`
-    /// exception will be raised.
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
     #[inline]
     pub unsafe fn push_str(self, s: &str) {
         ruby::rb_str_cat(self.raw(), s.as_ptr() as *const _, s.len() as _);
     }
 
+    /// Returns whether `idx` falls on the boundary of a char, as decoded
+    /// according to `self`'s actual [`encoding`](#method.encoding) (the same
+    /// decoding [`chars`](#method.chars) uses).
+    ///
+    /// The start (`0`) and the end (`self.len()`) are always boundaries.
+    ///
+    /// # Safety
+    ///
+    /// Same reasons as [`as_bytes`](#method.as_bytes).
+    pub unsafe fn is_char_boundary(self, idx: usize) -> bool {
+        idx == 0 || idx == self.len() || self.char_indices().any(|(i, _)| i == idx)
+    }
+
+    /// Shortens `self` to `new_len` bytes.
+    ///
+    /// If `new_len` is greater than or equal to `self`'s current length,
+    /// this has no effect.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` doesn't lie on a char boundary.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
+    pub unsafe fn truncate(self, new_len: usize) {
+        if new_len >= self.len() {
+            return;
+        }
+        assert!(
+            self.is_char_boundary(new_len),
+            "cannot truncate a String at a non-char-boundary index {}",
+            new_len,
+        );
+        ruby::rb_str_resize(self.raw(), new_len as c_long);
+    }
+
+    /// Removes the last char from `self` and returns it, or `None` if `self`
+    /// is empty.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
+    pub unsafe fn pop(self) -> Option<char> {
+        let (idx, ch) = self.char_indices().last()?;
+        ruby::rb_str_resize(self.raw(), idx as c_long);
+        Some(ch)
+    }
+
+    /// Inserts `c` into `self` at the byte position `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` doesn't lie on a char boundary, or is past the end of
+    /// `self`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
+    #[inline]
+    pub unsafe fn insert(self, idx: usize, c: char) {
+        self.insert_str(idx, c.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Inserts `s` into `self` at the byte position `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` doesn't lie on a char boundary, or is past the end of
+    /// `self`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
+    #[inline]
+    pub unsafe fn insert_str(self, idx: usize, s: &str) {
+        self.replace_range(idx..idx, s);
+    }
+
+    /// Removes and returns the char starting at byte position `idx`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `idx` doesn't lie on a char boundary, or is past the end of
+    /// `self`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
+    pub unsafe fn remove(self, idx: usize) -> char {
+        let mut indices = self.char_indices().peekable();
+        while let Some((i, ch)) = indices.next() {
+            if i == idx {
+                let next = indices.peek().map_or_else(|| self.len(), |&(j, _)| j);
+                self.replace_range(i..next, "");
+                return ch;
+            }
+        }
+        panic!("cannot remove a char at a non-boundary index {} in a String of length {}", idx, self.len());
+    }
+
+    /// Replaces the bytes of `self` in `range` with the contents of
+    /// `replacement`, growing or shrinking `self` as needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start or end of `range` don't lie on a char boundary,
+    /// or are past the end of `self`.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `self` is not frozen (see
+    /// [`is_frozen`](#method.is_frozen)) or else a `FrozenError` exception
+    /// will be raised.
+    pub unsafe fn replace_range<R>(self, range: R, replacement: &str)
+        where R: RangeBounds<usize>
+    {
+        use std::ops::Bound;
+
+        let len = self.len();
+        let start = match range.start_bound() {
+            Bound::Included(&n) => n,
+            Bound::Excluded(&n) => n + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&n) => n + 1,
+            Bound::Excluded(&n) => n,
+            Bound::Unbounded => len,
+        };
+        assert!(start <= end, "replace_range start is after its end");
+        assert!(end <= len, "replace_range end is out of bounds of the String");
+        assert!(
+            self.is_char_boundary(start) && self.is_char_boundary(end),
+            "replace_range called with a non-char-boundary index",
+        );
+
+        let bytes = self.as_bytes();
+        let mut new_bytes = Vec::with_capacity(start + replacement.len() + (len - end));
+        new_bytes.extend_from_slice(&bytes[..start]);
+        new_bytes.extend_from_slice(replacement.as_bytes());
+        new_bytes.extend_from_slice(&bytes[end..]);
+
+        ruby::rb_str_resize(self.raw(), new_bytes.len() as c_long);
+        let ptr = (*self.rstring()).start_mut() as *mut u8;
+        std::ptr::copy_nonoverlapping(new_bytes.as_ptr(), ptr, new_bytes.len());
+    }
+
     /// Returns the contents of `self` with an ellipsis (three dots) if it's
     /// longer than `len` _characters_.
     ///
@@ -676,8 +1423,167 @@ impl String {
     pub unsafe fn raw_unlock(self) {
         ruby::rb_str_unlocktmp(self.raw());
     }
+
+    /// Locks `self`'s buffer against reallocation for as long as the
+    /// returned guard lives, unlike [`with_lock`](#method.with_lock), which
+    /// only holds the lock for the duration of a closure.
+    ///
+    /// This is what makes borrowing a `&mut [u8]` out of `self` sound: as
+    /// long as the guard is alive, the VM can't move or resize the buffer
+    /// out from under it.
+    ///
+    /// # Safety
+    ///
+    /// The exception raised by the VM must be handled if `self` is already
+    /// locked.
+    #[inline]
+    pub unsafe fn lock_tmp(self) -> StrLockGuard {
+        self.raw_lock();
+        StrLockGuard { string: self }
+    }
+
+    /// Freezes `self` in place, returning `self` back.
+    ///
+    /// Unlike [`to_interned`](#method.to_interned), this does not deduplicate
+    /// `self` into the fstring table: it mirrors Ruby's own `String#freeze`
+    /// (`rb_obj_freeze`), which sets the frozen flag on the receiver and
+    /// always returns that same object, so any other reference to `self`
+    /// observes it becoming frozen too.
+    #[inline]
+    pub fn freeze(self) -> Self {
+        unsafe { Self::from_raw(ruby::rb_obj_freeze(self.raw())) }
+    }
+}
+
+/// An RAII guard that holds a [`String`](struct.String.html)'s temp lock
+/// (see [`is_locked`](struct.String.html#method.is_locked)) for its
+/// lifetime, created by [`String::lock_tmp`](struct.String.html#method.lock_tmp).
+///
+/// Unlocks the string via `rb_str_unlocktmp` on [`Drop`](#impl-Drop).
+pub struct StrLockGuard {
+    string: String,
 }
 
+impl StrLockGuard {
+    /// Returns the guarded string.
+    #[inline]
+    pub fn string(&self) -> String {
+        self.string
+    }
+
+    /// Returns the locked string's underlying bytes, mutably.
+    ///
+    /// # Safety
+    ///
+    /// The lock held by `self` guarantees the VM won't move or resize the
+    /// buffer for as long as it's held, but the caller must still ensure
+    /// the length of `self` isn't changed through the VM while the
+    /// returned slice is alive.
+    #[inline]
+    pub unsafe fn as_bytes_mut(&mut self) -> &mut [u8] {
+        let len = self.string.len();
+        let ptr = (*self.string.rstring()).start_mut() as *mut u8;
+        std::slice::from_raw_parts_mut(ptr, len)
+    }
+}
+
+impl Drop for StrLockGuard {
+    #[inline]
+    fn drop(&mut self) {
+        unsafe { self.string.raw_unlock() };
+    }
+}
+
+/// An iterator over the `char`s of a [`String`](struct.String.html),
+/// created by [`String::chars`](struct.String.html#method.chars).
+#[derive(Clone)]
+pub struct Chars<'a> {
+    ptr: *const c_char,
+    end: *const c_char,
+    enc: *mut ruby::rb_encoding,
+    _marker: std::marker::PhantomData<&'a String>,
+}
+
+impl Iterator for Chars<'_> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        if self.ptr >= self.end {
+            return None;
+        }
+        let mut len: c_int = 0;
+        let codepoint = unsafe {
+            ruby::rb_enc_codepoint_len(self.ptr, self.end, &mut len, self.enc)
+        };
+        self.ptr = unsafe { self.ptr.add(len as usize) };
+        Some(std::char::from_u32(codepoint).unwrap_or(std::char::REPLACEMENT_CHARACTER))
+    }
+}
+
+/// An iterator over the `char`s of a [`String`](struct.String.html) and
+/// their byte offsets, created by
+/// [`String::char_indices`](struct.String.html#method.char_indices).
+#[derive(Clone)]
+pub struct CharIndices<'a> {
+    chars: Chars<'a>,
+    start: *const c_char,
+}
+
+impl Iterator for CharIndices<'_> {
+    type Item = (usize, char);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        let index = unsafe { self.chars.ptr.offset_from(self.start) } as usize;
+        let c = self.chars.next()?;
+        Some((index, c))
+    }
+}
+
+/// An iterator over the byte sub-slices of a
+/// [`String`](struct.String.html) separated by a delimiter, created by
+/// [`String::split`](struct.String.html#method.split).
+#[derive(Clone)]
+pub struct Split<'a> {
+    rest: Option<&'a [u8]>,
+    delim: &'a [u8],
+}
+
+impl<'a> Iterator for Split<'a> {
+    type Item = &'a [u8];
+
+    fn next(&mut self) -> Option<&'a [u8]> {
+        let haystack = self.rest?;
+        match search::find(haystack, self.delim) {
+            Some(i) if !self.delim.is_empty() => {
+                let (head, tail) = haystack.split_at(i);
+                self.rest = Some(&tail[self.delim.len()..]);
+                Some(head)
+            }
+            _ => {
+                self.rest = None;
+                Some(haystack)
+            }
+        }
+    }
+}
+
+/// The error returned by
+/// [`String::from_utf16`](struct.String.html#method.from_utf16) when the
+/// input contains an unpaired surrogate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FromUtf16Error(());
+
+impl fmt::Display for FromUtf16Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "invalid utf-16: lone surrogate found".fmt(f)
+    }
+}
+
+impl std::error::Error for FromUtf16Error {}
+
 #[cfg(all(test, nightly))]
 mod benches {
     use test::{Bencher, black_box};
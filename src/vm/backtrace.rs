@@ -0,0 +1,94 @@
+//! Structured stack traces.
+
+use std::os::raw::c_void;
+use crate::{
+    prelude::*,
+    ruby::{self, VALUE},
+};
+
+/// A single frame of a backtrace, wrapping a Ruby `Thread::Backtrace::Location`.
+///
+/// See [`vm::backtrace_locations`](fn.backtrace_locations.html) and
+/// [`AnyException::backtrace_locations`](../exception/struct.AnyException.html#method.backtrace_locations).
+#[derive(Clone, Copy)]
+#[repr(transparent)]
+pub struct BacktraceLocation(AnyObject);
+
+impl AsRef<AnyObject> for BacktraceLocation {
+    #[inline]
+    fn as_ref(&self) -> &AnyObject { &self.0 }
+}
+
+impl From<BacktraceLocation> for AnyObject {
+    #[inline]
+    fn from(loc: BacktraceLocation) -> Self { loc.0 }
+}
+
+impl BacktraceLocation {
+    #[inline]
+    fn _new(obj: AnyObject) -> Self { BacktraceLocation(obj) }
+
+    /// Returns the absolute path of the file executing at this frame, or
+    /// `None` if there is none (e.g. code evaluated via `-e` or `eval`).
+    #[inline]
+    pub fn path(self) -> Option<crate::String> {
+        unsafe { self.0.call_unchecked("absolute_path") }.to_string()
+    }
+
+    /// Returns the line number executing at this frame.
+    #[inline]
+    pub fn lineno(self) -> u32 {
+        unsafe { self.0.call_unchecked("lineno") }
+            .to_integer()
+            .expect("`lineno` did not return an Integer")
+            .to_truncated()
+    }
+
+    /// Returns the label for this frame: the name of the method, block, or
+    /// top-level context being executed, without any class/module
+    /// qualification.
+    #[inline]
+    pub fn label(self) -> crate::String {
+        unsafe { self.0.call_unchecked("label") }
+            .to_string()
+            .expect("`label` did not return a String")
+    }
+}
+
+// Reads the elements of a Ruby `Array` directly via the C API. Backtrace
+// frame arrays are never exposed to user code as a typed `Array<T>`, so this
+// bypasses that wrapper rather than inventing one.
+fn _locations_from_array(ary: VALUE) -> Vec<BacktraceLocation> {
+    unsafe {
+        let len = ruby::rb_array_len(ary);
+        (0..len)
+            .map(|i| BacktraceLocation::_new(AnyObject::from_raw(ruby::rb_ary_entry(ary, i))))
+            .collect()
+    }
+}
+
+pub(crate) fn _locations_of(exc: AnyObject) -> Vec<BacktraceLocation> {
+    let locations = unsafe { exc.call_unchecked("backtrace_locations") };
+    match locations.to_array() {
+        Some(locations) => _locations_from_array(locations.raw()),
+        None => Vec::new(),
+    }
+}
+
+unsafe extern "C" fn _inspect(
+    dc: *const ruby::rb_debug_inspector_t,
+    _data: *mut c_void,
+) -> VALUE {
+    ruby::rb_debug_inspector_backtrace_locations(dc)
+}
+
+/// Captures the current call stack as a sequence of
+/// [`BacktraceLocation`](struct.BacktraceLocation.html)s, from the innermost
+/// frame outward.
+///
+/// See [`backtrace`](fn.backtrace.html) for the plain-`String` equivalent.
+#[inline]
+pub fn backtrace_locations() -> Vec<BacktraceLocation> {
+    let ary = unsafe { ruby::rb_debug_inspector_open(_inspect, std::ptr::null_mut()) };
+    _locations_from_array(ary)
+}
@@ -11,10 +11,12 @@ use crate::{
     ruby,
 };
 
+mod backtrace;
 mod eval;
 mod instr_seq;
 
 pub use self::{
+    backtrace::*,
     eval::*,
     instr_seq::*,
 };
@@ -179,7 +181,9 @@ pub unsafe fn require_with_protected(
 ) -> Result<bool> {
     // monomorphization
     unsafe fn require(file: String, safe: c_int) -> Result<ruby::VALUE> {
-        crate::protected_no_panic(|| ruby::rb_require_safe(file.raw(), safe))
+        crate::protected::exception_only(
+            crate::protected_no_panic(|| ruby::rb_require_safe(file.raw(), safe)),
+        )
     }
     // Convert to `bool` here for inlining
     Ok(require(file.into(), safe_level)? != 0)